@@ -0,0 +1,231 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use aoc_common::input::split_lines;
+use aoc_common::registry::Day;
+use aoc_common::{warnings, Args, ParseError};
+use crate::{days, inputs, CommandError};
+
+/// `aoc bench [day] [part]`: times parsing and solving on the real inputs
+pub fn bench(args: &Args) -> Result<(), CommandError> {
+    let day: Option<u32> = optional_positional(args, 1, "day")?;
+    let part: Option<u32> = optional_positional(args, 2, "part")?;
+    let warmup = option_number(args, "warmup", 3)?;
+    let iterations = option_number(args, "iterations", 10)?;
+    if iterations == 0 {
+        return Err(CommandError::Usage(String::from("At least one iteration is needed")));
+    }
+    if args.option("input").is_some() && day.is_none() {
+        return Err(CommandError::Usage(String::from("--input needs a day")));
+    }
+
+    let registry = days::registry();
+    let days: Vec<u32> = match day {
+        Some(day) if registry.get(day).is_none() => {
+            return Err(CommandError::Failed(format!("No solution for day {}", day)));
+        },
+        Some(day) => vec![day],
+        None => registry.days().collect()
+    };
+    let parts: Vec<u32> = part.map(|p| vec![p]).unwrap_or(vec![1, 2]);
+
+    let mut results: Vec<Measurement> = vec![];
+    for day in days {
+        let input = match args.option("input") {
+            Some(input) => input.into(),
+            None => inputs::find(day).unwrap_or_else(|| inputs::path(day))
+        };
+        if !input.exists() {
+            eprintln!("Skipping day {}: no input at {}", day, input.display());
+            continue;
+        }
+        let solution = registry.get(day).unwrap();
+        let content = inputs::read(&input.to_string_lossy()).map_err(CommandError::Failed)?;
+        let lines = split_lines(&content, solution.input_mode());
+        for warning in warnings::take() {
+            eprintln!("warning: {}: {}", input.display(), warning);
+        }
+
+        for &part in &parts {
+            let measurement = measure(solution, day, part, &lines, warmup, iterations)
+                .map_err(|e| CommandError::Failed(format!("Day {} does not parse its input: {}", day, e)))?;
+
+            match measurement {
+                Some(m) if !args.flag("markdown") => print_measurement(&m),
+                Some(m) => results.push(m),
+                None => {}
+            }
+        }
+    }
+
+    if args.flag("markdown") {
+        print!("{}", markdown_table(&results));
+    }
+
+    Ok(())
+}
+
+fn optional_positional(args: &Args, index: usize, what: &str) -> Result<Option<u32>, CommandError> {
+    if args.positional().len() <= index {
+        return Ok(None);
+    }
+    args.parse_positional(index, what).map(Some).map_err(CommandError::Usage)
+}
+
+fn option_number(args: &Args, name: &str, default: usize) -> Result<usize, CommandError> {
+    match args.option(name) {
+        Some(value) => value.parse().map_err(|_| CommandError::Usage(format!("Invalid --{}: {:?}", name, value))),
+        None => Ok(default)
+    }
+}
+
+struct Measurement {
+    day: u32,
+    part: u32,
+    parse: Stats,
+    solve: Stats
+}
+
+/// Runs `warmup` untimed rounds and then times `iterations` rounds of parsing and solving separately.
+/// Returns `None` if the part is not solved
+fn measure(solution: &dyn Day, day: u32, part: u32, lines: &[String], warmup: usize, iterations: usize)
+    -> Result<Option<Measurement>, ParseError>
+{
+    let input = solution.parse(lines)?;
+    if solution.solve(part, input.as_ref()).is_none() {
+        return Ok(None);
+    }
+
+    for _ in 0..warmup {
+        black_box(solution.parse(lines)?);
+        black_box(solution.solve(part, input.as_ref()));
+    }
+
+    let mut parse_samples: Vec<Duration> = vec![];
+    let mut solve_samples: Vec<Duration> = vec![];
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(solution.parse(lines)?);
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(solution.solve(part, input.as_ref()));
+        solve_samples.push(start.elapsed());
+    }
+
+    Ok(Some(Measurement {
+        day,
+        part,
+        parse: Stats::from(&parse_samples),
+        solve: Stats::from(&solve_samples)
+    }))
+}
+
+fn print_measurement(m: &Measurement) {
+    for (name, stats) in [("parse", &m.parse), ("solve", &m.solve)] {
+        println!(
+            "day {:>2} part {}  {}  mean {:>10}  median {:>10}  stddev {:>10}  ({} runs)",
+            m.day, m.part, name,
+            format_duration(stats.mean), format_duration(stats.median), format_duration(stats.stddev),
+            stats.runs
+        );
+    }
+}
+
+fn markdown_table(results: &[Measurement]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Parse mean | Parse median | Parse stddev | Solve mean | Solve median | Solve stddev |\n"
+    );
+    table.push_str("|----:|-----:|-----------:|-------------:|-------------:|-----------:|-------------:|-------------:|\n");
+
+    for m in results {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} |\n",
+            m.day, m.part,
+            format_duration(m.parse.mean), format_duration(m.parse.median), format_duration(m.parse.stddev),
+            format_duration(m.solve.mean), format_duration(m.solve.median), format_duration(m.solve.stddev)
+        ));
+    }
+
+    table
+}
+
+#[derive(Debug, PartialEq)]
+struct Stats {
+    mean: Duration,
+    median: Duration,
+    stddev: Duration,
+    runs: usize
+}
+
+impl Stats {
+    fn from(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / runs as u32;
+        let variance = sorted.iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / runs as f64;
+
+        Stats {
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            runs
+        }
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 2, 6, 8].iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let stats = Stats::from(&samples);
+
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert!((stats.stddev.as_secs_f64() * 1e3 - 5f64.sqrt()).abs() < 1e-6);
+        assert_eq!(stats.runs, 4);
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let stats = |ms| Stats::from(&[Duration::from_millis(ms), Duration::from_millis(ms + 2)]);
+        let table = markdown_table(&[Measurement { day: 6, part: 2, parse: stats(1), solve: stats(10) }]);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert!(lines[0].contains("| Parse stddev |"));
+        assert_eq!(lines[2], "| 6 | 2 | 2.00ms | 2.00ms | 1.00ms | 11.00ms | 11.00ms | 1.00ms |");
+        assert!(lines.iter().all(|l| l.matches('|').count() == 9));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+    }
+}
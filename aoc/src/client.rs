@@ -0,0 +1,148 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use aoc_common::Args;
+use ureq::Agent;
+use crate::{workspace, CommandError};
+
+/// Year of the puzzles solved in this workspace
+pub const YEAR: u32 = 2025;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"), " (github.com/AndreasBilke/aoc-2025)");
+
+/// Client of the Advent of Code website, configured by environment variables:
+///
+/// - `AOC_SESSION`: value of the `session` cookie of a logged in browser
+/// - `AOC_BASE_URL`: defaults to `https://adventofcode.com`
+/// - `AOC_CACHE_DIR`: defaults to `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`, must be outside of the repository
+/// - `AOC_MIN_INTERVAL`: seconds between two requests, defaults to 5
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+    agent: Agent
+}
+
+/// Response with a status which is not an error of the transport
+pub struct Response {
+    pub status: u16,
+    pub body: String
+}
+
+impl Client {
+    pub fn from_env() -> Result<Self, CommandError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+        let session = env::var("AOC_SESSION").ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        let cache_dir = match configured_cache_root() {
+            Some(dir) if workspace::contains(&dir) => return Err(CommandError::Failed(format!(
+                "Refusing the cache directory {}, it is inside the repository", dir.display()
+            ))),
+            Some(dir) => dir,
+            None => return Err(CommandError::Failed(String::from("No cache directory, set AOC_CACHE_DIR")))
+        };
+        let min_interval = match env::var("AOC_MIN_INTERVAL") {
+            Ok(seconds) => seconds.parse::<f64>().ok()
+                .filter(|s| *s >= 0.0)
+                .map(Duration::from_secs_f64)
+                .ok_or_else(|| CommandError::Failed(format!("Invalid AOC_MIN_INTERVAL: {:?}", seconds)))?,
+            Err(_) => DEFAULT_MIN_INTERVAL
+        };
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Ok(Client { base_url: base_url.trim_end_matches('/').to_string(), session, cache_dir, min_interval, agent })
+    }
+
+    /// Directory of the cached files of a day
+    pub fn cache_dir(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_dir.join(year.to_string()).join(format!("day-{}", day))
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response, CommandError> {
+        let session = self.session()?;
+        self.wait_for_interval()?;
+
+        let response = self.agent.get(format!("{}{}", self.base_url, path))
+            .header("Cookie", format!("session={}", session))
+            .call();
+        self.read(path, response)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, CommandError> {
+        let session = self.session()?;
+        self.wait_for_interval()?;
+
+        let response = self.agent.post(format!("{}{}", self.base_url, path))
+            .header("Cookie", format!("session={}", session))
+            .send_form(form.iter().copied());
+        self.read(path, response)
+    }
+
+    fn session(&self) -> Result<&str, CommandError> {
+        self.session.as_deref()
+            .ok_or_else(|| CommandError::Failed(String::from("AOC_SESSION is not set, copy the session cookie of the website into it")))
+    }
+
+    fn read(&self, path: &str, response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> Result<Response, CommandError> {
+        let failed = |e: ureq::Error| CommandError::Failed(format!("Request to {}{} failed: {}", self.base_url, path, e));
+
+        let mut response = response.map_err(failed)?;
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string().map_err(failed)?;
+
+        Ok(Response { status, body })
+    }
+
+    /// Sleeps until the minimum interval since the last request of any `aoc` process is over,
+    /// then records the time of this request in the cache directory
+    fn wait_for_interval(&self) -> Result<(), CommandError> {
+        let marker = self.cache_dir.join("last-request");
+        let last = fs::read_to_string(&marker).ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(elapsed) = last.and_then(|last| SystemTime::now().duration_since(last).ok()) {
+            thread::sleep(self.min_interval.saturating_sub(elapsed));
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        fs::create_dir_all(&self.cache_dir)
+            .and_then(|_| fs::write(&marker, now.to_string()))
+            .map_err(|e| CommandError::Failed(format!("Could not write {}: {}", marker.display(), e)))
+    }
+}
+
+/// Reads `--year`, which defaults to [`YEAR`]
+pub fn year(args: &Args) -> Result<u32, CommandError> {
+    match args.option("year") {
+        Some(year) => year.parse().map_err(|_| CommandError::Usage(format!("Invalid --year: {:?}", year))),
+        None => Ok(YEAR)
+    }
+}
+
+/// The cache directory, `None` if it is unknown or inside the repository, which must not hold plaintext inputs
+pub fn cache_root() -> Option<PathBuf> {
+    configured_cache_root().filter(|dir| !workspace::contains(dir))
+}
+
+/// `AOC_CACHE_DIR` or the cache directory of the user, `None` if neither is known
+fn configured_cache_root() -> Option<PathBuf> {
+    env::var_os("AOC_CACHE_DIR").map(PathBuf::from).or_else(default_cache_dir)
+}
+
+fn default_cache_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CACHE_HOME").map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .map(|dir| dir.join("aoc"))
+}
@@ -0,0 +1,18 @@
+use aoc_common::Registry;
+
+/// Registry with all solved days
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register::<day_1::Day1>(1);
+    registry.register::<day_2::Day2>(2);
+    registry.register::<day_3::Day3>(3);
+    registry.register::<day_4::Day4>(4);
+    registry.register::<day_5::Day5>(5);
+    registry.register::<day_6::Day6>(6);
+    registry.register::<day_7::Day7>(7);
+    registry.register::<day_8::Day8>(8);
+    registry.register::<day_9::Day9>(9);
+
+    registry
+}
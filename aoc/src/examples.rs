@@ -0,0 +1,39 @@
+use aoc_common::examples::{Manifest, MANIFEST};
+use aoc_common::Args;
+use crate::{days, workspace, CommandError};
+
+/// `aoc examples <day>`: solves the examples of `examples.toml` and compares them with the expected answers
+pub fn examples(args: &Args) -> Result<(), CommandError> {
+    if args.positional().len() != 2 {
+        return Err(CommandError::Usage(String::from("Expected a day")));
+    }
+    let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+
+    let registry = days::registry();
+    let solution = registry.get(day)
+        .ok_or_else(|| CommandError::Failed(format!("No solution for day {}", day)))?;
+    let dir = workspace::root().join(format!("day-{}", day));
+    let manifest = Manifest::load(&dir.join(MANIFEST)).map_err(CommandError::Failed)?;
+
+    let mut failures = 0;
+    for example in &manifest.examples {
+        for part in [1, 2] {
+            if example.answer(part).is_none() {
+                continue;
+            }
+
+            match example.check(&dir, solution, part) {
+                Ok(answer) => println!("PASS {} part {}: {}", example.name(), part, answer),
+                Err(e) => {
+                    println!("FAIL {} part {}: {}", example.name(), part, e.trim_end());
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(CommandError::Failed(format!("{} example(s) failed", n)))
+    }
+}
@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::Path;
+use aoc_common::input::STDIN;
+use aoc_common::Args;
+use crate::client::{self, Client};
+use crate::{inputs, CommandError};
+
+/// `aoc fetch <day> [--year <year>] [--output <file>] [--force]`: downloads the puzzle input of a day.
+///
+/// Inputs are cached per year and day outside of the repository, so the website is only asked once unless
+/// `--force` is given. The runner reads the input from the cache. `--output` copies it to a file outside
+/// of the repository, `-` prints it
+pub fn fetch(args: &Args) -> Result<(), CommandError> {
+    if args.positional().len() != 2 {
+        return Err(CommandError::Usage(String::from("Expected a day")));
+    }
+    let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+    if !(1..=25).contains(&day) {
+        return Err(CommandError::Usage(format!("Day {} is not part of the calendar", day)));
+    }
+    let year = client::year(args)?;
+
+    let client = Client::from_env()?;
+    let cached = client.cache_dir(year, day).join("input");
+    let input = match fs::read_to_string(&cached) {
+        Ok(input) if !args.flag("force") => input,
+        _ => {
+            let input = download(&client, year, day)?;
+            fs::create_dir_all(cached.parent().unwrap())
+                .and_then(|_| fs::write(&cached, &input))
+                .map_err(|e| CommandError::Failed(format!("Could not write {}: {}", cached.display(), e)))?;
+            input
+        }
+    };
+
+    match args.option("output") {
+        Some(STDIN) => print!("{}", input),
+        Some(output) => {
+            inputs::write(Path::new(output), &input).map_err(CommandError::Failed)?;
+            println!("Wrote {}", output);
+        },
+        None => println!("Input of day {} is in {}", day, cached.display())
+    }
+
+    Ok(())
+}
+
+fn download(client: &Client, year: u32, day: u32) -> Result<String, CommandError> {
+    let response = client.get(&format!("/{}/day/{}/input", year, day))?;
+
+    match response.status {
+        200 => Ok(response.body),
+        400 | 401 | 403 => Err(CommandError::Failed(String::from("The session was rejected, AOC_SESSION may have expired"))),
+        404 => Err(CommandError::Failed(format!("Day {} of {} is not unlocked yet", day, year))),
+        status => Err(CommandError::Failed(format!("Unexpected response {}: {}", status, response.body.trim())))
+    }
+}
@@ -0,0 +1,54 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use aoc_common::input::{read_source, STDIN};
+use crate::client::{self, YEAR};
+use crate::{vault, workspace};
+
+/// Location of the downloaded input of a day, in the cache outside of the repository.
+/// Falls back to [`local_path`] if there is no cache directory
+pub fn path(day: u32) -> PathBuf {
+    match client::cache_root() {
+        Some(cache) => cache.join(YEAR.to_string()).join(format!("day-{}", day)).join("input"),
+        None => local_path(day)
+    }
+}
+
+/// Plaintext input placed into the day by hand. It is ignored by git and only read, never written
+pub fn local_path(day: u32) -> PathBuf {
+    workspace::root().join(format!("day-{}", day)).join("input")
+}
+
+/// Location of the encrypted input of a day, which is committed
+pub fn vault_path(day: u32) -> PathBuf {
+    local_path(day).with_extension(vault::EXTENSION)
+}
+
+/// The plaintext input of a day: the one placed into the day, otherwise the downloaded one
+pub fn find_plaintext(day: u32) -> Option<PathBuf> {
+    [local_path(day), path(day)].into_iter().find(|path| path.exists())
+}
+
+/// The input of a day: a plaintext file if there is one, otherwise the vault
+pub fn find(day: u32) -> Option<PathBuf> {
+    find_plaintext(day).or_else(|| Some(vault_path(day)).filter(|path| path.exists()))
+}
+
+/// Reads an input file, `-` is stdin. Vaults are decrypted in memory
+pub fn read(input: &str) -> Result<String, String> {
+    let path = Path::new(input);
+    if path.extension().is_some_and(|extension| extension == vault::EXTENSION) {
+        return vault::read(path);
+    }
+
+    let name = if input == STDIN { "stdin" } else { input };
+    read_source(input).map_err(|e| format!("Could not read {}: {}", name, e))
+}
+
+/// Writes a plaintext input. Paths inside the repository are refused, so real inputs cannot be committed by accident
+pub fn write(path: &Path, content: &str) -> Result<(), String> {
+    if workspace::contains(path) {
+        return Err(format!("Refusing to write the plaintext input to {}, which is inside the repository", path.display()));
+    }
+
+    fs::write(path, content).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
@@ -0,0 +1,230 @@
+use std::env;
+use std::io::{self, Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use aoc_common::checked;
+use aoc_common::input::{read_source, STDIN};
+use aoc_common::log::{self, Level};
+use aoc_common::Args;
+use serde::{Deserialize, Serialize};
+use crate::bench::format_duration;
+use crate::run::{solve_content, Record};
+use crate::{days, CommandError};
+
+/// Hidden command of the child process, `aoc __solve <day> <part> <name>` reads the input from stdin
+pub const CHILD_COMMAND: &str = "__solve";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+const MIB: u64 = 1024 * 1024;
+
+/// Limits of a solution which runs in a child process
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Limits {
+    /// Wall-clock time after which the child is killed
+    pub timeout: Option<Duration>,
+    /// Cap of the address space of the child in MiB. Only supported on Unix
+    pub memory_mib: Option<u64>
+}
+
+impl Limits {
+    /// Reads `--timeout <seconds>` and `--memory <MiB>`
+    pub fn from(args: &Args) -> Result<Self, CommandError> {
+        let timeout = args.option("timeout")
+            .map(|t| t.parse::<f64>().ok()
+                .filter(|t| *t > 0.0)
+                .and_then(|t| Duration::try_from_secs_f64(t).ok())
+                .ok_or_else(|| CommandError::Usage(format!("Invalid --timeout: {:?}", t))))
+            .transpose()?;
+        let memory_mib = args.option("memory")
+            .map(|m| m.parse::<u64>().ok()
+                .filter(|m| *m > 0 && m.checked_mul(MIB).is_some())
+                .ok_or_else(|| CommandError::Usage(format!("Invalid --memory: {:?}", m))))
+            .transpose()?;
+
+        Ok(Limits { timeout, memory_mib })
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.memory_mib.is_some()
+    }
+
+    /// The memory cap in bytes, `None` if there is none or it does not fit into a `u64`
+    fn memory_bytes(&self) -> Option<u64> {
+        self.memory_mib?.checked_mul(MIB)
+    }
+}
+
+/// What the child process prints on stdout
+#[derive(Serialize, Deserialize)]
+struct Report {
+    record: Record,
+    diagnostic: Option<String>
+}
+
+/// Solves the part in a child process of this executable, which gets the input on stdin.
+///
+/// A child which exceeds the limits fails with "timed out" or "out of memory" instead of taking the whole run down
+pub fn solve(day: u32, part: u32, name: &str, content: &str, limits: &Limits) -> Record {
+    let record = Record::new(day, part, name);
+    let mut command = match env::current_exe() {
+        Ok(exe) => Command::new(exe),
+        Err(e) => return record.failed(format!("Could not find the aoc executable: {}", e), None)
+    };
+    command.args([CHILD_COMMAND, &day.to_string(), &part.to_string(), name])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if checked::is_enabled() {
+        command.arg("--checked");
+    }
+    if log::enabled(Level::Trace) {
+        command.arg("--trace");
+    } else if log::enabled(Level::Debug) {
+        command.arg("-v");
+    }
+    if let Some(bytes) = limits.memory_bytes() {
+        limit_memory(&mut command, bytes);
+    }
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => return record.failed(format!("Could not start the child process: {}", e), None)
+    };
+
+    // the pipes are served by threads, so a child which writes a lot cannot block before the timeout
+    let mut stdin = child.stdin.take().unwrap();
+    let content = content.to_string();
+    let writer = thread::spawn(move || {
+        // fails if the child is killed before it read everything, which is reported below
+        let _ = stdin.write_all(content.as_bytes());
+    });
+    let stdout = read_in_background(child.stdout.take().unwrap());
+    let stderr = read_in_background(child.stderr.take().unwrap());
+
+    let status = wait(&mut child, limits.timeout);
+    let _ = writer.join();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let status = match status {
+        Ok(Some(status)) => status,
+        Ok(None) => {
+            let timeout = limits.timeout.unwrap();
+            return record.failed(format!("Timed out after {}", format_duration(timeout)), None);
+        },
+        Err(e) => return record.failed(format!("Could not wait for the child process: {}", e), None)
+    };
+
+    match serde_json::from_str::<Report>(&stdout) {
+        Ok(report) => {
+            eprint!("{}", stderr);
+            Record { diagnostic: report.diagnostic, ..report.record }
+        },
+        // Rust aborts with the first message if an allocation fails, reading the input fails with the second
+        Err(_) if stderr.contains("memory allocation of") || stderr.contains("out of memory") => {
+            let error = format!("Out of memory (limit {} MiB)", limits.memory_mib.unwrap_or_default());
+            record.failed(error, None)
+        },
+        Err(_) => {
+            let cause = stderr.lines().last().unwrap_or_default();
+            record.failed(format!("Child process failed ({}): {}", status, cause), None)
+        }
+    }
+}
+
+/// Entry point of the child process started by [`solve`]
+pub fn child(args: &Args) -> Result<(), CommandError> {
+    if args.positional().len() != 4 {
+        return Err(CommandError::Usage(String::from("Expected a day, a part and the name of the input")));
+    }
+    let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+    let part: u32 = args.parse_positional(2, "part").map_err(CommandError::Usage)?;
+    let name = &args.positional()[3];
+    if args.flag("checked") {
+        checked::enable();
+    }
+
+    let registry = days::registry();
+    let solution = registry.get(day)
+        .ok_or_else(|| CommandError::Failed(format!("No solution for day {}", day)))?;
+    let content = read_source(STDIN)
+        .map_err(|e| CommandError::Failed(format!("Could not read the input from stdin: {}", e)))?;
+
+    let mut record = solve_content(solution, day, part, name, &content);
+    let report = Report { diagnostic: record.diagnostic.take(), record };
+    println!("{}", serde_json::to_string(&report).unwrap());
+
+    Ok(())
+}
+
+fn read_in_background<R: Read + Send + 'static>(mut pipe: R) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        let _ = pipe.read_to_string(&mut output);
+        output
+    })
+}
+
+/// Waits for the child and kills it once the timeout is over. Returns `None` on timeout
+fn wait(child: &mut Child, timeout: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+    let Some(timeout) = timeout else {
+        return child.wait().map(Some);
+    };
+
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) {
+    use std::os::unix::process::CommandExt;
+
+    let limit = libc::rlimit { rlim_cur: bytes as libc::rlim_t, rlim_max: bytes as libc::rlim_t };
+    // SAFETY: the closure runs between fork and exec and only calls setrlimit, which is async-signal-safe
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_command: &mut Command, _bytes: u64) {
+    eprintln!("warning: --memory is only supported on Unix, the child process runs without a memory cap");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits() {
+        let args = |a: &[&str]| Args::parse(a.iter().map(|s| s.to_string()), &["timeout", "memory"]).unwrap();
+
+        let limits = Limits::from(&args(&["run", "--timeout", "1.5", "--memory=512"])).unwrap();
+        assert_eq!(limits.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(limits.memory_mib, Some(512));
+        assert!(limits.is_set());
+
+        assert!(!Limits::from(&args(&["run"])).unwrap().is_set());
+        for invalid in [["--timeout", "0"], ["--timeout", "-1"], ["--timeout", "inf"], ["--timeout", "NaN"], ["--memory", "0"]] {
+            assert!(Limits::from(&args(&["run", invalid[0], invalid[1]])).is_err(), "{:?}", invalid);
+        }
+        assert!(Limits::from(&args(&["run", "--memory", &u64::MAX.to_string()])).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::workspace;
+
+const HEADER: &str = "# day part input-hash answer\n";
+
+/// Accepted answers, keyed by day, part and the hash of the input they belong to
+#[derive(Debug, Default, PartialEq)]
+pub struct Ledger {
+    entries: BTreeMap<(u32, u32, String), String>
+}
+
+impl Ledger {
+    pub fn path() -> PathBuf {
+        workspace::root().join("answers.ledger")
+    }
+
+    /// Loads the ledger. A missing file is an empty ledger
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Ledger::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        Ledger::from(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn from(content: &str) -> Result<Self, String> {
+        let mut ledger = Ledger::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            if fields.len() != 4 {
+                return Err(format!("line {}: expected day, part, input hash and answer", index + 1));
+            }
+            let day = fields[0].parse().map_err(|_| format!("line {}: invalid day", index + 1))?;
+            let part = fields[1].parse().map_err(|_| format!("line {}: invalid part", index + 1))?;
+
+            ledger.insert(day, part, fields[2], fields[3]);
+        }
+
+        Ok(ledger)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u32, part: u32, input_hash: &str) -> Option<&str> {
+        self.entries.get(&(day, part, input_hash.to_string())).map(String::as_str)
+    }
+
+    /// Number of answers recorded for a day, for any input
+    pub fn recorded(&self, day: u32) -> usize {
+        self.entries.keys().filter(|(d, _, _)| *d == day).count()
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, input_hash: &str, answer: &str) {
+        self.entries.insert((day, part, input_hash.to_string()), answer.to_string());
+    }
+}
+
+impl std::fmt::Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(HEADER)?;
+        for ((day, part, input_hash), answer) in &self.entries {
+            writeln!(f, "{} {} {} {}", day, part, input_hash, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut ledger = Ledger::default();
+        ledger.insert(3, 2, "00ff", "3121910778619");
+        ledger.insert(3, 1, "00ff", "357");
+
+        let content = ledger.to_string();
+        assert_eq!(content, "# day part input-hash answer\n3 1 00ff 357\n3 2 00ff 3121910778619\n");
+        assert_eq!(Ledger::from(&content), Ok(ledger));
+    }
+
+    #[test]
+    fn test_recorded() {
+        let ledger = Ledger::from("3 1 00ff 357\n3 2 00ff 3121910778619\n5 1 0a0a 3").unwrap();
+
+        assert_eq!((ledger.recorded(3), ledger.recorded(5), ledger.recorded(4)), (2, 1, 0));
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        assert!(Ledger::from("3 1 00ff").is_err());
+    }
+}
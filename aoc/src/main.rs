@@ -0,0 +1,77 @@
+mod bench;
+mod client;
+mod days;
+mod examples;
+mod fetch;
+mod inputs;
+mod isolate;
+mod ledger;
+mod run;
+mod scaffold;
+mod submit;
+mod vault;
+mod verify;
+mod watch;
+mod workspace;
+
+use std::env;
+use std::process::ExitCode;
+use aoc_common::log::{self, Level};
+use aoc_common::Args;
+
+const USAGE: &str = "Usage: aoc [-v | -vv | --trace] <command>
+    aoc run <day> <part> [<input>...] [--checked] [--format text|json] [--timeout <seconds>] [--memory <MiB>]
+    aoc run --all [--checked] [--format text|json] [--timeout <seconds>] [--memory <MiB>]
+    aoc new <day> --answer1 <answer> [--answer2 <answer>]
+    aoc bench [<day> [<part>]] [--input <file>] [--warmup <n>] [--iterations <n>] [--markdown]
+    aoc verify [<day>] [--record] [--checked]
+    aoc examples <day>
+    aoc watch <day> [--interval <ms>]
+    aoc fetch <day> [--year <year>] [--output <file>] [--force]
+    aoc submit <day> <part> [<answer>] [--year <year>]
+    aoc vault add <day> [<file>]
+    aoc vault show <day> [--output <file>]";
+
+/// Options which take a value
+const VALUE_OPTIONS: &[&str] = &["answer1", "answer2", "input", "warmup", "iterations", "format", "timeout", "memory", "interval", "year", "output"];
+
+/// Error of a subcommand
+#[derive(Debug)]
+pub enum CommandError {
+    /// Wrong arguments, reported together with the usage
+    Usage(String),
+    /// The command failed, the message is printed as it is
+    Failed(String)
+}
+
+fn main() -> ExitCode {
+    let result = Args::parse(env::args().skip(1), VALUE_OPTIONS)
+        .map_err(CommandError::Usage)
+        .inspect(|args| log::set_level(Level::from(args)))
+        .and_then(|args| match args.positional().first().map(String::as_str) {
+            Some("run") => run::run(&args),
+            Some("new") => scaffold::new_day(&args),
+            Some("bench") => bench::bench(&args),
+            Some("verify") => verify::verify(&args),
+            Some("examples") => examples::examples(&args),
+            Some("watch") => watch::watch(&args),
+            Some("fetch") => fetch::fetch(&args),
+            Some("submit") => submit::submit(&args),
+            Some("vault") => vault::vault(&args),
+            Some(isolate::CHILD_COMMAND) => isolate::child(&args),
+            Some(command) => Err(CommandError::Usage(format!("Unknown command {:?}", command))),
+            None => Err(CommandError::Usage(String::from("Missing command")))
+        });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CommandError::Usage(message)) => {
+            eprintln!("{}\n{}", message, USAGE);
+            ExitCode::FAILURE
+        },
+        Err(CommandError::Failed(message)) => {
+            eprintln!("{}", message.trim_end());
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use aoc_common::checked;
+use aoc_common::hash::input_hash;
+use aoc_common::input::{split_lines, STDIN};
+use aoc_common::output::format_parse_error;
+use aoc_common::registry::Day;
+use aoc_common::{print_result, warnings, Args, ParseError, Registry};
+use serde::{Deserialize, Serialize};
+use crate::bench::format_duration;
+use crate::isolate::{self, Limits};
+use crate::{days, inputs, CommandError};
+
+/// How results are printed
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    /// One JSON object per line, see [`Record`]
+    Json
+}
+
+impl Format {
+    fn from(args: &Args) -> Result<Self, CommandError> {
+        match args.option("format") {
+            None | Some("text") => Ok(Format::Text),
+            Some("json") => Ok(Format::Json),
+            Some(other) => Err(CommandError::Usage(format!("Unknown format {:?}", other)))
+        }
+    }
+}
+
+/// `aoc run <day> <part> [<input>...] [--checked] [--format text|json]`: solves the part for every input.
+///
+/// Without inputs, or for `-`, the input is read from stdin. With several inputs each result is prefixed with its input.
+/// `aoc run --all` solves every registered day, see [`run_all`].
+/// With `--timeout <seconds>` or `--memory <MiB>` every part runs in a child process, see [`isolate`]
+pub fn run(args: &Args) -> Result<(), CommandError> {
+    if args.flag("checked") {
+        checked::enable();
+    }
+    let limits = Limits::from(args)?;
+    if args.flag("all") {
+        if args.positional().len() != 1 {
+            return Err(CommandError::Usage(String::from("--all takes no day, part or input")));
+        }
+        return run_all(Format::from(args)?, &limits);
+    }
+
+    if args.positional().len() < 3 {
+        return Err(CommandError::Usage(String::from("Expected a day, a part and optional input files")));
+    }
+    let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+    let part: u32 = args.parse_positional(2, "part").map_err(CommandError::Usage)?;
+    let format = Format::from(args)?;
+
+    let inputs: Vec<&str> = match &args.positional()[3..] {
+        [] => vec![STDIN],
+        files => files.iter().map(String::as_str).collect()
+    };
+    if inputs.iter().filter(|&&i| i == STDIN).count() > 1 {
+        return Err(CommandError::Usage(String::from("Stdin can only be read once")));
+    }
+
+    let registry = days::registry();
+    let solution = registry.get(day)
+        .ok_or_else(|| CommandError::Failed(format!("No solution for day {}", day)))?;
+
+    if let ([input], Format::Text) = (&inputs[..], format) {
+        let record = solve(solution, day, part, input, &limits);
+        print_warnings(&record);
+        return match record.answer {
+            Some(answer) => {
+                print_result(answer);
+                Ok(())
+            },
+            None => Err(CommandError::Failed(record.diagnostic.unwrap_or_default()))
+        };
+    }
+
+    let mut failures = 0;
+    for input in inputs {
+        let record = solve(solution, day, part, input, &limits);
+        if record.error.is_some() {
+            failures += 1;
+        }
+
+        match format {
+            Format::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+            Format::Text => {
+                print_warnings(&record);
+                match (&record.answer, &record.diagnostic) {
+                    (Some(answer), _) => println!("{}: {}", record.input, answer),
+                    (None, diagnostic) => eprintln!("{}", diagnostic.as_deref().unwrap_or_default().trim_end())
+                }
+            }
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(CommandError::Failed(format!("{} input(s) failed", n)))
+    }
+}
+
+/// Result of one part for one input
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub input_hash: Option<String>,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+    /// Why the part was not run, e.g. a missing input. Skipped parts are not failures
+    pub skipped: Option<String>,
+    /// The error as it is shown on the terminal, parse errors include the input line
+    #[serde(skip)]
+    pub diagnostic: Option<String>,
+    /// The part has no solution yet
+    pub unsolved: bool
+}
+
+impl Record {
+    pub fn new(day: u32, part: u32, input: &str) -> Self {
+        Record { day, part, input: input.to_string(), ..Record::default() }
+    }
+
+    pub fn skipped(day: u32, part: u32, input: &str, reason: String) -> Self {
+        Record { skipped: Some(reason), ..Record::new(day, part, input) }
+    }
+
+    pub fn failed(mut self, error: String, diagnostic: Option<String>) -> Self {
+        self.diagnostic = Some(diagnostic.unwrap_or_else(|| error.clone()));
+        self.error = Some(error);
+        self.warnings.extend(warnings::take());
+        self
+    }
+}
+
+/// Reads the input and solves the part, in this process or in a child process if limits are set
+fn solve(solution: &dyn Day, day: u32, part: u32, input: &str, limits: &Limits) -> Record {
+    let name = display_name(input);
+    let content = match inputs::read(input) {
+        Ok(content) => content,
+        Err(e) => return Record::new(day, part, name).failed(e, None)
+    };
+
+    if limits.is_set() {
+        isolate::solve(day, part, name, &content, limits)
+    } else {
+        solve_content(solution, day, part, name, &content)
+    }
+}
+
+/// Solves the part for an input which is already read. Panics of the solution are turned into failed records
+pub fn solve_content(solution: &dyn Day, day: u32, part: u32, name: &str, content: &str) -> Record {
+    warnings::take();
+    let mut record = Record::new(day, part, name);
+    record.input_hash = Some(input_hash(content));
+    let lines = split_lines(content, solution.input_mode());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| -> Result<_, ParseError> {
+        let start = Instant::now();
+        let parsed = solution.parse(&lines)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = checked::catch(|| solution.solve(part, parsed.as_ref()));
+        Ok((parse_time, answer, start.elapsed()))
+    }));
+
+    let (parse_time, answer, solve_time) = match result {
+        Ok(Ok(solved)) => solved,
+        Ok(Err(e)) => {
+            let e = e.in_file(name);
+            let diagnostic = format_parse_error(&e, &lines);
+            return record.failed(e.to_string(), Some(diagnostic));
+        },
+        Err(payload) => return record.failed(format!("Panicked: {}", panic_message(payload.as_ref())), None)
+    };
+    record.parse_ns = Some(parse_time.as_nanos() as u64);
+
+    let answer = match answer {
+        Ok(Some(answer)) => answer,
+        Ok(None) => {
+            record.unsolved = true;
+            return record.failed(format!("No solution for day {} part {}", day, part), None);
+        },
+        Err(overflow) => return record.failed(format!("{}: {}", name, overflow), None)
+    };
+    record.solve_ns = Some(solve_time.as_nanos() as u64);
+    record.answer = Some(answer.to_string());
+    record.warnings = warnings::take();
+
+    record
+}
+
+/// Solves both parts of every registered day on its real input, spread over one thread per core.
+///
+/// Prints a table ordered by day, or the JSON records. Days without input are reported as skipped, parts without
+/// solution as unsolved. Fails if any part panics or cannot be solved, or if no day has an input
+fn run_all(format: Format, limits: &Limits) -> Result<(), CommandError> {
+    let registry = days::registry();
+    let mut jobs: Vec<(u32, u32, String)> = vec![];
+    let mut missing: Vec<u32> = vec![];
+    for day in registry.days() {
+        match inputs::find(day) {
+            Some(input) => jobs.extend([1, 2].map(|part| (day, part, input.to_string_lossy().to_string()))),
+            None => missing.push(day)
+        }
+    }
+
+    let mut records = solve_parallel(&registry, &jobs, limits);
+    // a part which is not solved yet is not a failure of the run
+    records.iter_mut().filter(|r| r.unsolved).for_each(|r| r.error = None);
+    if format == Format::Json {
+        records.extend(missing.iter().flat_map(|&day| [1, 2].map(|part| {
+            let input = inputs::path(day);
+            Record::skipped(day, part, &input.to_string_lossy(), format!("No input at {}", input.display()))
+        })));
+    }
+    records.sort_by_key(|r| (r.day, r.part));
+    let failures = records.iter().filter(|r| r.error.is_some()).count();
+
+    match format {
+        Format::Json => records.iter().for_each(|r| println!("{}", serde_json::to_string(r).unwrap())),
+        Format::Text => {
+            for day in missing {
+                eprintln!("Skipping day {}: no input at {}", day, inputs::path(day).display());
+            }
+            records.iter().for_each(print_warnings);
+            print!("{}", summary_table(&records));
+        }
+    }
+
+    match failures {
+        _ if jobs.is_empty() => Err(CommandError::Failed(String::from("No day has an input, nothing was run"))),
+        0 => Ok(()),
+        n => Err(CommandError::Failed(format!("{} part(s) failed", n)))
+    }
+}
+
+/// Solves the `(day, part, input)` jobs on a pool of scoped threads
+fn solve_parallel(registry: &Registry, jobs: &[(u32, u32, String)], limits: &Limits) -> Vec<Record> {
+    let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(1).min(jobs.len());
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let next_job = &next_job;
+            scope.spawn(move || {
+                while let Some((day, part, input)) = jobs.get(next_job.fetch_add(1, Ordering::Relaxed)) {
+                    let solution = registry.get(*day).unwrap();
+
+                    sender.send(solve(solution, *day, *part, input, limits)).unwrap();
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    receiver.into_iter().collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+fn summary_table(records: &[Record]) -> String {
+    let time = |ns: Option<u64>| ns.map(|ns| format_duration(Duration::from_nanos(ns))).unwrap_or_default();
+    let rows: Vec<[String; 5]> = records.iter()
+        .map(|r| [
+            format!("{:>3}  {:>4}", r.day, r.part),
+            r.answer.clone().unwrap_or_default(),
+            time(r.parse_ns),
+            time(r.solve_ns),
+            match (&r.error, r.unsolved) {
+                (Some(error), _) => error.clone(),
+                (None, true) => String::from("unsolved"),
+                (None, false) => String::from("ok")
+            }
+        ])
+        .collect();
+
+    let header = [String::from("day  part"), String::from("answer"), String::from("parse"), String::from("solve"), String::from("status")];
+    let widths: Vec<usize> = (0..5)
+        .map(|column| rows.iter().chain([&header]).map(|row| row[column].chars().count()).max().unwrap())
+        .collect();
+
+    let mut table = String::new();
+    for row in [&header].into_iter().chain(&rows) {
+        table.push_str(&format!(
+            "{:<w0$}  {:<w1$}  {:>w2$}  {:>w3$}  {}\n",
+            row[0], row[1], row[2], row[3], row[4],
+            w0 = widths[0], w1 = widths[1], w2 = widths[2], w3 = widths[3]
+        ));
+    }
+
+    table
+}
+
+fn print_warnings(record: &Record) {
+    for warning in &record.warnings {
+        eprintln!("warning: {}: {}", record.input, warning);
+    }
+}
+
+fn display_name(input: &str) -> &str {
+    if input == STDIN { "<stdin>" } else { input }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_json() {
+        let record = Record {
+            day: 3,
+            part: 1,
+            input: String::from("day-3/input"),
+            error: Some(String::from("Could not read day-3/input")),
+            diagnostic: Some(String::from("not serialized")),
+            ..Record::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            "{\"day\":3,\"part\":1,\"input\":\"day-3/input\",\"input_hash\":null,\"answer\":null,\
+             \"parse_ns\":null,\"solve_ns\":null,\"warnings\":[],\"error\":\"Could not read day-3/input\",\"skipped\":null,\"unsolved\":false}"
+        );
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let records = vec![
+            Record { day: 3, part: 1, answer: Some(String::from("357")), parse_ns: Some(1500), solve_ns: Some(20), ..Record::default() },
+            Record { day: 8, part: 2, parse_ns: Some(3000), unsolved: true, ..Record::default() },
+            Record { day: 12, part: 2, error: Some(String::from("Panicked: boom")), ..Record::default() }
+        ];
+
+        assert_eq!(summary_table(&records), "\
+day  part  answer   parse  solve  status
+  3     1  357     1.50µs   20ns  ok
+  8     2          3.00µs         unsolved
+ 12     2                         Panicked: boom
+");
+    }
+}
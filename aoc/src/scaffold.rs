@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::Path;
+use aoc_common::Args;
+use crate::{workspace, CommandError};
+
+/// `aoc new <day>`: creates `day-<day>` from the files in `templates/day` and registers it in the runner.
+///
+/// Templates may use the placeholders `{{day}}`, `{{answer1}}` and `{{part2}}`.
+/// The answers are the expected example results and are inserted as TOML values: integers as they are, anything
+/// else as quoted string. The answer of part 1 is required. Without an answer of part 2, `{{part2}}` becomes a
+/// comment and the example does not check that part, which the template leaves unsolved
+pub fn new_day(args: &Args) -> Result<(), CommandError> {
+    if args.positional().len() != 2 {
+        return Err(CommandError::Usage(String::from("Expected a day")));
+    }
+    let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+    if !(1..=25).contains(&day) {
+        return Err(CommandError::Usage(format!("Day {} is not part of the calendar", day)));
+    }
+
+    let root = workspace::root();
+    let day_dir = root.join(format!("day-{}", day));
+    if day_dir.exists() {
+        return Err(CommandError::Failed(format!("{} already exists", day_dir.display())));
+    }
+
+    let answer1 = args.option("answer1")
+        .ok_or_else(|| CommandError::Usage(String::from("Expected the example answer of part 1 as --answer1")))?;
+    let part2 = match args.option("answer2") {
+        Some(answer) => format!("part2 = {}", toml_answer(answer)),
+        None => String::from("# part2 = <example answer, once part 2 is solved>")
+    };
+
+    let placeholders = [
+        ("{{day}}", day.to_string()),
+        ("{{answer1}}", toml_answer(answer1)),
+        ("{{part2}}", part2)
+    ];
+    // a half-created day would block the next attempt, which refuses existing directories
+    let created = copy_template(&root.join("templates/day"), &day_dir, &placeholders)
+        .map_err(|e| CommandError::Failed(format!("Could not create {}: {}", day_dir.display(), e)))
+        .and_then(|_| register(&root, day));
+    if let Err(e) = created {
+        let _ = fs::remove_dir_all(&day_dir);
+        return Err(e);
+    }
+
+    println!("Created {}", day_dir.display());
+    Ok(())
+}
+
+/// An answer as TOML value for `examples.toml`
+fn toml_answer(answer: &str) -> String {
+    match answer.parse::<i64>() {
+        Ok(number) => number.to_string(),
+        Err(_) => toml::Value::String(answer.to_string()).to_string()
+    }
+}
+
+fn copy_template(template: &Path, target: &Path, placeholders: &[(&str, String)]) -> std::io::Result<()> {
+    fs::create_dir_all(target)?;
+
+    for entry in fs::read_dir(template)? {
+        let entry = entry?;
+        let target = target.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, placeholders)?;
+        } else {
+            let content = placeholders.iter().fold(fs::read_to_string(entry.path())?, |content, (key, value)| {
+                content.replace(key, value)
+            });
+            fs::write(target, content)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the day as dependency of the runner and to its registry
+fn register(root: &Path, day: u32) -> Result<(), CommandError> {
+    let manifest = root.join("aoc/Cargo.toml");
+    let dependency = format!("day-{} = {{ path = \"../day-{}\" }}", day, day);
+    let original = update_file(&manifest, |content| insert_line(content, &dependency, day, |line| {
+        day_prefix(line.strip_prefix("day-")?)
+    }))?;
+
+    let days = root.join("aoc/src/days.rs");
+    let registration = format!("    registry.register::<day_{}::Day{}>({});", day, day, day);
+    update_file(&days, |content| insert_line(content, &registration, day, |line| {
+        day_prefix(line.strip_prefix("    registry.register::<day_")?)
+    })).map(|_| ()).inspect_err(|_| {
+        // the dependency alone would point to the removed directory
+        let _ = fs::write(&manifest, original);
+    })
+}
+
+/// Returns the previous content
+fn update_file<F>(path: &Path, update: F) -> Result<String, CommandError>
+where
+    F: Fn(&str) -> Option<String>
+{
+    let content = fs::read_to_string(path)
+        .map_err(|e| CommandError::Failed(format!("Could not read {}: {}", path.display(), e)))?;
+    let updated = update(&content)
+        .ok_or_else(|| CommandError::Failed(format!("Could not find the list of days in {}", path.display())))?;
+
+    fs::write(path, updated)
+        .map_err(|e| CommandError::Failed(format!("Could not write {}: {}", path.display(), e)))?;
+    Ok(content)
+}
+
+/// Reads the day number at the start of `text`
+fn day_prefix(text: &str) -> Option<u32> {
+    let digits: String = text.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Inserts `line` into the lines for which `day_of` returns a day, so that they stay ordered by day.
+/// Returns `None` if there are no such lines
+fn insert_line<F>(content: &str, line: &str, day: u32, day_of: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<u32>
+{
+    let mut lines: Vec<&str> = content.lines().collect();
+    let day_lines: Vec<(usize, u32)> = lines.iter().enumerate()
+        .filter_map(|(index, l)| day_of(l).map(|d| (index, d)))
+        .collect();
+
+    let position = match day_lines.iter().find(|(_, d)| *d > day) {
+        Some((index, _)) => *index,
+        None => day_lines.last()?.0 + 1
+    };
+    lines.insert(position, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_line() {
+        let content = "[dependencies]\naoc-common = { path = \"../common\" }\nday-1 = 1\nday-12 = 12\n";
+        let day_of = |line: &str| day_prefix(line.strip_prefix("day-")?);
+
+        let expected = "[dependencies]\naoc-common = { path = \"../common\" }\nday-1 = 1\nday-3 = 3\nday-12 = 12\n";
+        assert_eq!(insert_line(content, "day-3 = 3", 3, day_of).unwrap(), expected);
+
+        let expected = "[dependencies]\naoc-common = { path = \"../common\" }\nday-1 = 1\nday-12 = 12\nday-13 = 13\n";
+        assert_eq!(insert_line(content, "day-13 = 13", 13, day_of).unwrap(), expected);
+
+        assert_eq!(insert_line("[dependencies]\n", "day-1 = 1", 1, day_of), None);
+    }
+
+    #[test]
+    fn test_toml_answer() {
+        let read = |answer: &str| {
+            let table: toml::Table = toml::from_str(&format!("part1 = {}", toml_answer(answer))).unwrap();
+            table["part1"].clone()
+        };
+
+        assert_eq!(read("4277556"), toml::Value::Integer(4277556));
+        assert_eq!(read("abc"), toml::Value::String(String::from("abc")));
+        assert_eq!(read("99999999999999999999"), toml::Value::String(String::from("99999999999999999999")));
+        assert_eq!(read("a\"b'c"), toml::Value::String(String::from("a\"b'c")));
+    }
+}
@@ -0,0 +1,404 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use aoc_common::Args;
+use crate::client::{self, Client};
+use crate::run::solve_content;
+use crate::{days, inputs, CommandError};
+
+const HEADER: &str = "# time part outcome wait answer\n";
+
+/// `aoc submit <day> <part> [<answer>] [--year <year>]`: posts an answer to the website.
+///
+/// Without an answer the part is solved on the real input first. Every outcome is kept in a history next to the
+/// cached input, which is checked before posting: known wrong answers, answers outside the too high / too low
+/// bounds and answers while the website asks to wait are refused locally
+pub fn submit(args: &Args) -> Result<(), CommandError> {
+    if !(3..=4).contains(&args.positional().len()) {
+        return Err(CommandError::Usage(String::from("Expected a day, a part and an optional answer")));
+    }
+    let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+    let part: u32 = args.parse_positional(2, "part").map_err(CommandError::Usage)?;
+    if !(1..=2).contains(&part) {
+        return Err(CommandError::Usage(format!("Part {} does not exist", part)));
+    }
+    let year = client::year(args)?;
+    let answer = match args.positional().get(3) {
+        Some(answer) => answer.trim().to_string(),
+        None => solve(day, part)?
+    };
+    // a history line needs an answer without line breaks
+    if answer.is_empty() || answer.contains(['\n', '\r']) {
+        return Err(CommandError::Usage(format!("Invalid answer {:?}", answer)));
+    }
+
+    let client = Client::from_env()?;
+    let history_path = client.cache_dir(year, day).join("submissions");
+    let mut history = History::load(&history_path).map_err(CommandError::Failed)?;
+    if let Some(reason) = history.refusal(part, &answer, now()) {
+        return Err(CommandError::Failed(format!("Not submitting {}: {}", answer, reason)));
+    }
+
+    let level = part.to_string();
+    let response = client.post_form(&format!("/{}/day/{}/answer", year, day), &[("level", &level), ("answer", &answer)])?;
+    if response.status != 200 {
+        return Err(CommandError::Failed(format!("Unexpected response {}: {}", response.status, response.body.trim())));
+    }
+    let (outcome, wait) = parse_response(&response.body)
+        .ok_or_else(|| CommandError::Failed(format!("Could not understand the response:\n{}", article_text(&response.body))))?;
+
+    history.push(Submission { time: now(), part, outcome, wait: wait.map(|w| w.as_secs()), answer: answer.clone() });
+    fs::create_dir_all(history_path.parent().unwrap())
+        .map_err(|e| CommandError::Failed(format!("Could not create {}: {}", history_path.display(), e)))?;
+    history.save(&history_path).map_err(CommandError::Failed)?;
+
+    let wait = wait.map(|w| format!(", wait {} before the next answer", format_wait(w.as_secs()))).unwrap_or_default();
+    match outcome {
+        Outcome::Correct => {
+            println!("{} is correct for day {} part {}", answer, day, part);
+            Ok(())
+        },
+        outcome => Err(CommandError::Failed(format!("{}: {}{}", answer, outcome.description(), wait)))
+    }
+}
+
+/// Solves the part on the real input of the day
+fn solve(day: u32, part: u32) -> Result<String, CommandError> {
+    let registry = days::registry();
+    let solution = registry.get(day)
+        .ok_or_else(|| CommandError::Failed(format!("No solution for day {}", day)))?;
+    let input = inputs::find(day)
+        .ok_or_else(|| CommandError::Failed(format!("No input at {}", inputs::path(day).display())))?;
+    let content = inputs::read(&input.to_string_lossy()).map_err(CommandError::Failed)?;
+
+    let record = solve_content(solution, day, part, &input.to_string_lossy(), &content);
+    record.answer.ok_or_else(|| CommandError::Failed(record.diagnostic.unwrap_or_default()))
+}
+
+/// Waiting time as the website shows it, e.g. `1m 23s`
+fn format_wait(seconds: u64) -> String {
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{}s", seconds),
+        (minutes, 0) => format!("{}m", minutes),
+        (minutes, seconds) => format!("{}m {}s", minutes, seconds)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// What the website said about an answer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint
+    Wrong,
+    /// Submitted too soon after the previous answer, the answer was not checked
+    Throttled,
+    /// The part is already solved or not unlocked yet
+    WrongLevel
+}
+
+impl Outcome {
+    const NAMES: [(Outcome, &'static str); 6] = [
+        (Outcome::Correct, "correct"),
+        (Outcome::TooHigh, "too-high"),
+        (Outcome::TooLow, "too-low"),
+        (Outcome::Wrong, "wrong"),
+        (Outcome::Throttled, "throttled"),
+        (Outcome::WrongLevel, "wrong-level")
+    ];
+
+    fn description(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::Throttled => "not checked, the previous answer was too recent",
+            Outcome::WrongLevel => "not checked, the part is already solved or still locked"
+        }
+    }
+
+    /// The answer was checked and is wrong
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (_, name) = Outcome::NAMES.iter().find(|(outcome, _)| outcome == self).unwrap();
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Outcome::NAMES.iter().find(|(_, name)| *name == s).map(|(outcome, _)| *outcome).ok_or(())
+    }
+}
+
+/// Reads the outcome and the time to wait before the next answer from the answer page
+pub fn parse_response(html: &str) -> Option<(Outcome, Option<Duration>)> {
+    let text = article_text(html);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::Throttled
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        return None;
+    };
+
+    Some((outcome, parse_wait(&text)))
+}
+
+/// Text of the `<article>` of a page, without tags
+fn article_text(html: &str) -> String {
+    let article = html.find("<article")
+        .and_then(|start| html[start..].find("</article>").map(|end| &html[start..start + end]))
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "you have 1m 23s left to wait", "please wait one minute" or "please wait 5 minutes"
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(end) = text.find(" left to wait") {
+        let left = &text[text[..end].rfind("have ")? + "have ".len()..end];
+        let seconds = left.split_whitespace()
+            .map(|amount| {
+                let (unit_start, _) = amount.char_indices().last()?;
+                let (number, unit) = amount.split_at(unit_start);
+                let factor = match unit {
+                    "h" => 3600,
+                    "m" => 60,
+                    "s" => 1,
+                    _ => return None
+                };
+                number.parse::<u64>().ok().map(|n| n * factor)
+            })
+            .sum::<Option<u64>>()?;
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let start = text.find("wait ")?;
+    let mut words = text[start + "wait ".len()..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?
+    };
+    words.next().filter(|unit| unit.starts_with("minute"))?;
+
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// One posted answer
+#[derive(Debug, PartialEq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch
+    pub time: u64,
+    pub part: u32,
+    pub outcome: Outcome,
+    /// Seconds the website asked to wait before the next answer
+    pub wait: Option<u64>,
+    pub answer: String
+}
+
+/// Submissions of one day, oldest first
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    submissions: Vec<Submission>
+}
+
+impl History {
+    /// Loads the history. A missing file is an empty history
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        History::from(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn from(content: &str) -> Result<Self, String> {
+        let mut history = History::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = |field: &str| format!("line {}: invalid {}", index + 1, field);
+            let fields: Vec<&str> = line.splitn(5, ' ').collect();
+            if fields.len() != 5 {
+                return Err(format!("line {}: expected time, part, outcome, wait and answer", index + 1));
+            }
+            history.push(Submission {
+                time: fields[0].parse().map_err(|_| invalid("time"))?,
+                part: fields[1].parse().map_err(|_| invalid("part"))?,
+                outcome: fields[2].parse().map_err(|_| invalid("outcome"))?,
+                wait: match fields[3] {
+                    "-" => None,
+                    wait => Some(wait.parse().map_err(|_| invalid("wait"))?)
+                },
+                answer: fields[4].to_string()
+            });
+        }
+
+        Ok(history)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Why `answer` must not be posted for `part` at `now`, `None` if it may be posted
+    pub fn refusal(&self, part: u32, answer: &str, now: u64) -> Option<String> {
+        let of_part = || self.submissions.iter().filter(move |s| s.part == part);
+
+        if let Some(correct) = of_part().find(|s| s.outcome == Outcome::Correct) {
+            return Some(format!("part {} is already solved with {}", part, correct.answer));
+        }
+        if let Some(rejected) = of_part().find(|s| s.outcome.is_wrong() && s.answer == answer) {
+            return Some(format!("it was already rejected as {}", rejected.outcome.description()));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome: Outcome| of_part()
+                .filter(move |s| s.outcome == outcome)
+                .filter_map(|s| s.answer.parse::<i128>().ok());
+            if let Some(high) = bound(Outcome::TooHigh).filter(|&high| value >= high).min() {
+                return Some(format!("{} was too high", high));
+            }
+            if let Some(low) = bound(Outcome::TooLow).filter(|&low| value <= low).max() {
+                return Some(format!("{} was too low", low));
+            }
+        }
+
+        let until = self.submissions.iter()
+            .filter_map(|s| s.wait.map(|wait| s.time + wait))
+            .max()
+            .filter(|&until| until > now)?;
+        Some(format!("the website asked to wait another {}", format_wait(until - now)))
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(HEADER)?;
+        for s in &self.submissions {
+            let wait = s.wait.map(|w| w.to_string()).unwrap_or_else(|| String::from("-"));
+            writeln!(f, "{} {} {} {} {}", s.time, s.part, s.outcome, wait, s.answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", article)
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page("That's the right answer!  You are <em>one gold star</em> closer.")),
+            Some((Outcome::Correct, None))
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  \
+                Please wait one minute before trying again.")),
+            Some((Outcome::TooHigh, Some(Duration::from_secs(60))))
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, make sure you're using the full input \
+                data.  please wait 5 minutes before trying again.")),
+            Some((Outcome::Wrong, Some(Duration::from_secs(300))))
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before \
+                trying again.  You have 1m 23s left to wait.")),
+            Some((Outcome::Throttled, Some(Duration::from_secs(83))))
+        );
+        assert_eq!(
+            parse_response(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Some((Outcome::WrongLevel, None))
+        );
+        assert_eq!(parse_response(&page("Something else")), None);
+    }
+
+    #[test]
+    fn test_parse_wait_unknown_unit() {
+        assert_eq!(parse_wait("You have 1m 23é left to wait."), None);
+        assert_eq!(parse_wait("You have 2h left to wait."), Some(Duration::from_secs(7200)));
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let mut history = History::default();
+        history.push(Submission { time: 100, part: 1, outcome: Outcome::TooLow, wait: Some(60), answer: String::from("12") });
+        history.push(Submission { time: 200, part: 1, outcome: Outcome::Correct, wait: None, answer: String::from("357") });
+
+        let content = history.to_string();
+        assert_eq!(content, "# time part outcome wait answer\n100 1 too-low 60 12\n200 1 correct - 357\n");
+        assert_eq!(History::from(&content), Ok(history));
+        assert!(History::from("100 1 maybe - 12").is_err());
+    }
+
+    #[test]
+    fn test_refusal() {
+        let mut history = History::default();
+        history.push(Submission { time: 100, part: 2, outcome: Outcome::TooHigh, wait: Some(60), answer: String::from("500") });
+        history.push(Submission { time: 200, part: 2, outcome: Outcome::TooLow, wait: Some(60), answer: String::from("100") });
+
+        assert_eq!(history.refusal(2, "100", 1000), Some(String::from("it was already rejected as too low")));
+        assert_eq!(history.refusal(2, "600", 1000), Some(String::from("500 was too high")));
+        assert_eq!(history.refusal(2, "50", 1000), Some(String::from("100 was too low")));
+        assert_eq!(history.refusal(2, "300", 1000), None);
+        assert_eq!(history.refusal(1, "600", 1000), None);
+        assert_eq!(history.refusal(1, "600", 230), Some(String::from("the website asked to wait another 30s")));
+
+        history.push(Submission { time: 300, part: 2, outcome: Outcome::Correct, wait: None, answer: String::from("300") });
+        assert_eq!(history.refusal(2, "301", 1000), Some(String::from("part 2 is already solved with 300")));
+    }
+}
@@ -0,0 +1,137 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use aoc_common::input::{read_source, STDIN};
+use aoc_common::Args;
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use crate::{inputs, CommandError};
+
+/// Extension of encrypted inputs, `day-<day>/input.vault`
+pub const EXTENSION: &str = "vault";
+
+const MAGIC: &[u8] = b"aoc-vault 1\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// `aoc vault add <day> [<file>]` encrypts the input of a day into `day-<day>/input.vault`, which can be committed.
+/// The file defaults to the plaintext input of the day, see [`inputs::find_plaintext`], `-` reads stdin.
+///
+/// `aoc vault show <day> [--output <file>]` prints the decrypted input. Plaintext is never written into the repository.
+///
+/// The passphrase of the team is read from `AOC_VAULT_PASSPHRASE`
+pub fn vault(args: &Args) -> Result<(), CommandError> {
+    let positional = args.positional();
+    if positional.len() < 3 {
+        return Err(CommandError::Usage(String::from("Expected add or show and a day")));
+    }
+    let day: u32 = args.parse_positional(2, "day").map_err(CommandError::Usage)?;
+
+    match (positional[1].as_str(), positional.len()) {
+        ("add", 3 | 4) => {
+            let source = match positional.get(3) {
+                Some(source) => source.clone(),
+                None => inputs::find_plaintext(day)
+                    .ok_or_else(|| CommandError::Failed(format!("No input at {}", inputs::path(day).display())))?
+                    .to_string_lossy().to_string()
+            };
+            let plaintext = read_source(&source)
+                .map_err(|e| CommandError::Failed(format!("Could not read {}: {}", source, e)))?;
+            let target = inputs::vault_path(day);
+            let encrypted = encrypt(plaintext.as_bytes(), &passphrase().map_err(CommandError::Failed)?);
+            fs::write(&target, encrypted)
+                .map_err(|e| CommandError::Failed(format!("Could not write {}: {}", target.display(), e)))?;
+
+            println!("Encrypted {} into {}", source, target.display());
+            Ok(())
+        },
+        ("show", 3) => {
+            let plaintext = read(&inputs::vault_path(day)).map_err(CommandError::Failed)?;
+            match args.option("output") {
+                None | Some(STDIN) => print!("{}", plaintext),
+                Some(output) => inputs::write(Path::new(output), &plaintext).map_err(CommandError::Failed)?
+            }
+            Ok(())
+        },
+        (command, _) => Err(CommandError::Usage(format!("Unknown vault command {:?} or wrong arguments", command)))
+    }
+}
+
+/// Reads and decrypts a vault with the passphrase of the team
+pub fn read(path: &Path) -> Result<String, String> {
+    let data = fs::read(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    let plaintext = decrypt(&data, &passphrase()?)
+        .map_err(|e| format!("Could not decrypt {}: {}", path.display(), e))?;
+
+    String::from_utf8(plaintext).map_err(|_| format!("{} does not contain text", path.display()))
+}
+
+fn passphrase() -> Result<String, String> {
+    env::var("AOC_VAULT_PASSPHRASE").ok()
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| String::from("AOC_VAULT_PASSPHRASE is not set, ask the team for the passphrase of the vault"))
+}
+
+/// Encrypts with ChaCha20-Poly1305 and a key derived by Argon2 from the passphrase and a random salt.
+/// The result is the magic line, the salt, the nonce and the ciphertext
+pub fn encrypt(plaintext: &[u8], passphrase: &str) -> Vec<u8> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt));
+    let ciphertext = cipher.encrypt(&nonce, plaintext).expect("Input fits into a ChaCha20-Poly1305 message");
+
+    [MAGIC, &salt, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(data: &[u8], passphrase: &str) -> Result<Vec<u8>, String> {
+    let data = data.strip_prefix(MAGIC).ok_or_else(|| String::from("not a vault"))?;
+    if data.len() < SALT_LEN + NONCE_LEN {
+        return Err(String::from("the vault is truncated"));
+    }
+    let (salt, data) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, salt));
+    cipher.decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| String::from("wrong passphrase or damaged vault"))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Key {
+    let mut key = Key::default();
+    Argon2::default().hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .expect("Salt and key length are valid for Argon2");
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let encrypted = encrypt(b"987654321111111\n", "team secret");
+        assert!(encrypted.starts_with(MAGIC));
+        assert!(!encrypted.windows(15).any(|w| w == b"987654321111111"));
+        assert_eq!(decrypt(&encrypted, "team secret"), Ok(b"987654321111111\n".to_vec()));
+
+        // a new salt and nonce every time
+        assert_ne!(encrypt(b"987654321111111\n", "team secret"), encrypted);
+    }
+
+    #[test]
+    fn test_decrypt_errors() {
+        let encrypted = encrypt(b"input", "team secret");
+        assert_eq!(decrypt(&encrypted, "guess"), Err(String::from("wrong passphrase or damaged vault")));
+
+        let mut damaged = encrypted.clone();
+        *damaged.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&damaged, "team secret").is_err());
+
+        assert_eq!(decrypt(b"input", "team secret"), Err(String::from("not a vault")));
+        assert_eq!(decrypt(MAGIC, "team secret"), Err(String::from("the vault is truncated")));
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_common::checked;
+use aoc_common::hash::input_hash;
+use aoc_common::input::split_lines;
+use aoc_common::{warnings, Args};
+use crate::ledger::Ledger;
+use crate::{days, inputs, CommandError};
+
+/// `aoc verify [day]`: runs the real inputs and compares the answers with the ledger.
+///
+/// With `--record` answers that are not in the ledger yet are added. Recorded answers are never replaced.
+/// A day without input fails if the ledger has answers for it, so a lost input does not pass unnoticed.
+/// `--checked` reports arithmetic overflows instead of wrapping
+pub fn verify(args: &Args) -> Result<(), CommandError> {
+    if args.flag("checked") {
+        checked::enable();
+    }
+
+    let registry = days::registry();
+    let days: Vec<u32> = match args.positional().len() {
+        1 => registry.days().collect(),
+        2 => {
+            let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+            if registry.get(day).is_none() {
+                return Err(CommandError::Failed(format!("No solution for day {}", day)));
+            }
+            vec![day]
+        },
+        _ => return Err(CommandError::Usage(String::from("Expected at most a day")))
+    };
+
+    let ledger_path = Ledger::path();
+    let mut ledger = Ledger::load(&ledger_path).map_err(CommandError::Failed)?;
+    let mut failures = 0;
+    let mut recorded = 0;
+
+    for day in days {
+        let Some(input) = inputs::find(day) else {
+            match ledger.recorded(day) {
+                0 => println!("day {:>2}          no input at {}", day, inputs::path(day).display()),
+                n => {
+                    println!("day {:>2}          MISSING input at {}, the ledger has {} answer(s)", day, inputs::path(day).display(), n);
+                    failures += 1;
+                }
+            }
+            continue;
+        };
+        let content = match inputs::read(&input.to_string_lossy()) {
+            Ok(content) => content,
+            Err(e) => {
+                println!("day {:>2}          {}", day, e);
+                failures += 1;
+                continue;
+            }
+        };
+        let hash = input_hash(&content);
+        let solution = registry.get(day).unwrap();
+        let lines = split_lines(&content, solution.input_mode());
+        for warning in warnings::take() {
+            println!("day {:>2}          warning: {}", day, warning);
+        }
+        let parsed = match solution.parse(&lines) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!("day {:>2}          PARSE ERROR {}", day, e.in_file(&input.to_string_lossy()));
+                failures += 1;
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let answer = match checked::catch(|| solution.solve(part, parsed.as_ref())) {
+                Ok(Some(answer)) => answer,
+                Ok(None) => continue,
+                Err(overflow) => {
+                    println!("day {:>2} part {}  OVERFLOW in {}: {}", day, part, overflow.site, overflow.operation);
+                    failures += 1;
+                    continue;
+                }
+            };
+            let answer = answer.to_string();
+
+            match ledger.get(day, part, &hash) {
+                Some(expected) if expected == answer => println!("day {:>2} part {}  ok {}", day, part, answer),
+                Some(expected) => {
+                    println!("day {:>2} part {}  MISMATCH expected {}, got {}", day, part, expected, answer);
+                    failures += 1;
+                },
+                None if args.flag("record") => {
+                    println!("day {:>2} part {}  recorded {}", day, part, answer);
+                    ledger.insert(day, part, &hash, &answer);
+                    recorded += 1;
+                },
+                None => println!("day {:>2} part {}  new {} (not in the ledger, use --record)", day, part, answer)
+            }
+        }
+    }
+
+    if recorded > 0 {
+        ledger.save(&ledger_path).map_err(CommandError::Failed)?;
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(CommandError::Failed(format!("{} check(s) failed", n)))
+    }
+}
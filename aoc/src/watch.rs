@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use aoc_common::Args;
+use crate::{inputs, workspace, CommandError};
+
+/// Modification times of all watched files
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// `aoc watch <day> [--interval <ms>]`: rebuilds the runner whenever a file of the day or of `common` changes,
+/// then checks the examples and solves the real input. Runs until it is interrupted
+pub fn watch(args: &Args) -> Result<(), CommandError> {
+    if args.positional().len() != 2 {
+        return Err(CommandError::Usage(String::from("Expected a day")));
+    }
+    let day: u32 = args.parse_positional(1, "day").map_err(CommandError::Usage)?;
+    let interval = match args.option("interval") {
+        Some(ms) => ms.parse().map(Duration::from_millis)
+            .map_err(|_| CommandError::Usage(format!("Invalid --interval: {:?}", ms)))?,
+        None => Duration::from_millis(500)
+    };
+
+    let root = workspace::root();
+    let day_dir = root.join(format!("day-{}", day));
+    if !day_dir.is_dir() {
+        return Err(CommandError::Failed(format!("{} does not exist", day_dir.display())));
+    }
+    let watched = [day_dir, root.join("common")];
+    // the path has to be taken before the first build replaces the executable
+    let exe = env::current_exe()
+        .map_err(|e| CommandError::Failed(format!("Could not find the aoc executable: {}", e)))?;
+
+    let mut last: Option<Snapshot> = None;
+    loop {
+        let current = snapshot(&watched);
+        if last.as_ref() != Some(&current) {
+            if last.is_some() {
+                println!();
+            }
+            println!("=== day {}: rebuilding", day);
+            if build(&root) {
+                check(&exe, day);
+            }
+            last = Some(current);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+/// Collects the modification times of all files below `dirs`, without build output
+fn snapshot(dirs: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for dir in dirs {
+        collect(dir, &mut snapshot);
+    }
+
+    snapshot
+}
+
+fn collect(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return; // removed while walking
+    };
+
+    if metadata.is_dir() {
+        if path.file_name().is_some_and(|name| name == "target") {
+            return;
+        }
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect(&entry.path(), snapshot);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_path_buf(), modified);
+    }
+}
+
+/// Builds the runner with the profile of the running executable. Returns whether the build succeeded
+fn build(root: &Path) -> bool {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let mut command = Command::new(cargo);
+    command.args(["build", "--quiet", "-p", "aoc"]).current_dir(root);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    match command.status() {
+        Ok(status) if status.success() => true,
+        Ok(_) => {
+            println!("=== build failed, waiting for changes");
+            false
+        },
+        Err(e) => {
+            println!("=== could not run cargo: {}", e);
+            false
+        }
+    }
+}
+
+/// Runs the examples and the real input with the freshly built executable
+fn check(exe: &Path, day: u32) {
+    println!("=== examples");
+    let _ = Command::new(exe).args(["examples", &day.to_string()]).status();
+
+    let Some(input) = inputs::find(day) else {
+        println!("=== no input at {}", inputs::path(day).display());
+        return;
+    };
+    for part in ["1", "2"] {
+        println!("=== part {}", part);
+        let _ = Command::new(exe).args(["run", &day.to_string(), part, &input.to_string_lossy()]).status();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc-watch-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        fs::write(dir.join("target/ignored"), "").unwrap();
+
+        let before = snapshot(std::slice::from_ref(&dir));
+        assert_eq!(before.keys().collect::<Vec<_>>(), vec![&dir.join("src/lib.rs")]);
+
+        fs::write(dir.join("input"), "").unwrap();
+        assert_ne!(snapshot(std::slice::from_ref(&dir)), before);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
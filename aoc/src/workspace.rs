@@ -0,0 +1,36 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Root directory of the workspace the runner was built in
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate is part of the workspace")
+        .to_path_buf()
+}
+
+/// Whether `path` would end up inside the repository, also for files and directories which do not exist yet
+pub fn contains(path: &Path) -> bool {
+    let absolute = env::current_dir().map(|dir| dir.join(path)).unwrap_or_else(|_| path.to_path_buf());
+    // the missing rest is created below the nearest existing directory
+    let Some(existing) = absolute.ancestors().find(|dir| dir.exists()) else {
+        return false;
+    };
+    let (Ok(existing), Ok(root)) = (existing.canonicalize(), root().canonicalize()) else {
+        return false;
+    };
+
+    existing.starts_with(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        assert!(contains(&root().join("day-3/input")));
+        assert!(contains(&root().join("target/cache/2025/day-3")));
+        assert!(!contains(&env::temp_dir().join("input")));
+    }
+}
@@ -0,0 +1,133 @@
+mod stub;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+use stub::{aoc, temp_dir, Stub};
+
+fn input_server() -> Stub {
+    Stub::start(|request| match request.path.as_str() {
+        "/2025/day/3/input" => (200, String::from("987654321111111\n811111111111119\n")),
+        "/2025/day/25/input" => (404, String::from("Please don't repeatedly request this endpoint before it unlocks!")),
+        _ => (400, String::from("Puzzle inputs differ by user.  Please log in to get your puzzle input."))
+    })
+}
+
+#[test]
+fn test_fetch_and_cache() {
+    let stub = input_server();
+    let cache = temp_dir("fetch-cache");
+
+    let output = aoc(&stub, &cache, &["fetch", "3", "--output", "-"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "987654321111111\n811111111111119\n");
+    assert_eq!(fs::read_to_string(cache.join("2025/day-3/input")).unwrap(), "987654321111111\n811111111111119\n");
+
+    let requests = stub.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].body, "");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    assert!(requests[0].header("user-agent").is_some_and(|agent| agent.starts_with("aoc-runner/")));
+
+    // served from the cache
+    let target = cache.join("input");
+    let output = aoc(&stub, &cache, &["fetch", "3", "--output", target.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(fs::read_to_string(&target).unwrap(), "987654321111111\n811111111111119\n");
+    assert_eq!(stub.requests().len(), 1);
+
+    let output = aoc(&stub, &cache, &["fetch", "3", "--output", "-", "--force"]);
+    assert!(output.status.success());
+    assert_eq!(stub.requests().len(), 2);
+
+    fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn test_fetch_stays_outside_the_repository() {
+    let stub = input_server();
+    let cache = temp_dir("fetch-outside");
+
+    let output = aoc(&stub, &cache, &["fetch", "3"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let cached = cache.join("2025/day-3/input");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("Input of day 3 is in {}\n", cached.display()));
+    assert!(cached.exists());
+
+    let inside = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day-3/fetched-input");
+    let output = aoc(&stub, &cache, &["fetch", "3", "--output", inside.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Refusing to write the plaintext input"));
+    assert!(!inside.exists());
+
+    fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn test_fetch_refuses_cache_in_the_repository() {
+    let stub = input_server();
+    let cache = Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/aoc-cache-in-repository");
+
+    let output = aoc(&stub, &cache, &["fetch", "3", "--output", "-"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Refusing the cache directory "));
+    assert!(stub.requests().is_empty());
+    assert!(!cache.exists());
+
+    // nor are inputs looked up there
+    let output = aoc(&stub, &cache, &["run", "--all", "--format", "json"]);
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("aoc-cache-in-repository"));
+}
+
+#[test]
+fn test_fetch_errors() {
+    let stub = input_server();
+    let cache = temp_dir("fetch-errors");
+
+    let output = aoc(&stub, &cache, &["fetch", "25", "--output", "-"]);
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Day 25 of 2025 is not unlocked yet\n");
+    assert!(!cache.join("2025/day-25").exists());
+
+    let output = aoc(&stub, &cache, &["fetch", "3", "--year", "2024", "--output", "-"]);
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "The session was rejected, AOC_SESSION may have expired\n");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "3", "--output", "-"])
+        .env("AOC_BASE_URL", &stub.base_url)
+        .env("AOC_CACHE_DIR", &cache)
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("AOC_SESSION is not set"));
+    assert_eq!(stub.requests().len(), 2);
+
+    fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn test_min_interval() {
+    let stub = input_server();
+    let cache = temp_dir("fetch-interval");
+
+    let start = Instant::now();
+    for _ in 0..2 {
+        let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["fetch", "3", "--output", "-", "--force"])
+            .env("AOC_BASE_URL", &stub.base_url)
+            .env("AOC_CACHE_DIR", &cache)
+            .env("AOC_SESSION", "test-session")
+            .env("AOC_MIN_INTERVAL", "0.5")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+    assert!(start.elapsed() >= Duration::from_millis(500));
+    assert_eq!(stub.requests().len(), 2);
+
+    fs::remove_dir_all(&cache).unwrap();
+}
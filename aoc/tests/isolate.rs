@@ -0,0 +1,53 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// Writes an input file for one test
+fn input(name: &str, content: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    fs::write(&path, content).unwrap();
+    path
+}
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).output().unwrap()
+}
+
+#[test]
+fn test_timeout() {
+    // far too many ids to check within the timeout
+    let path = input("timeout", "1-3000000000\n");
+
+    let output = aoc(&["run", "2", "1", path.to_str().unwrap(), "--timeout", "0.2"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Timed out after 200.00ms"), "{}", stderr);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_out_of_memory() {
+    // the child alone needs more than the cap to read the input
+    let path = input("memory", &"1 ".repeat(24 * 1024 * 1024));
+
+    let output = aoc(&["run", "6", "1", path.to_str().unwrap(), "--memory", "32"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Out of memory (limit 32 MiB)"), "{}", stderr);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_invalid_limits() {
+    for limit in [["--timeout", "inf"], ["--timeout", "NaN"], ["--memory", "18446744073709551615"]] {
+        let output = aoc(&["run", "6", "1", "-", limit[0], limit[1]]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success());
+        assert!(stderr.contains(&format!("Invalid {}", limit[0])), "{}", stderr);
+    }
+}
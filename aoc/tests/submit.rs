@@ -0,0 +1,122 @@
+mod stub;
+
+use std::fs;
+use stub::{aoc, temp_dir, Stub};
+
+fn page(article: &str) -> String {
+    format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", article)
+}
+
+/// Accepts 357 for day 3 part 1, everything below is too low and everything above too high
+fn answer_server() -> Stub {
+    Stub::start(|request| {
+        if request.method != "POST" || request.path != "/2025/day/3/answer" {
+            return (404, String::from("404 Not Found"));
+        }
+        let form: Vec<(&str, &str)> = request.body.split('&').filter_map(|field| field.split_once('=')).collect();
+        let answer: i64 = form.iter().find(|(name, _)| *name == "answer").unwrap().1.parse().unwrap();
+
+        let article = match (form.contains(&("level", "1")), answer) {
+            (false, _) => "You don't seem to be solving the right level.  Did you already complete it?",
+            (true, 357) => "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+            (true, a) if a < 357 => "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+            (true, _) => "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+        };
+        (200, page(article))
+    })
+}
+
+#[test]
+fn test_submit() {
+    let stub = answer_server();
+    let cache = temp_dir("submit");
+    let history = cache.join("2025/day-3/submissions");
+
+    let output = aoc(&stub, &cache, &["submit", "3", "1", "400"]);
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "400: too high, wait 1m before the next answer\n");
+
+    let requests = stub.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].header("content-type"), Some("application/x-www-form-urlencoded"));
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    assert_eq!(requests[0].body, "level=1&answer=400");
+
+    // the website asked to wait, so nothing is posted
+    let output = aoc(&stub, &cache, &["submit", "3", "1", "300"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Not submitting 300: the website asked to wait another "));
+    assert_eq!(stub.requests().len(), 1);
+
+    // pretend the minute is over
+    let content = fs::read_to_string(&history).unwrap();
+    let (header, line) = content.split_once('\n').unwrap();
+    let (time, rest) = line.split_once(' ').unwrap();
+    let earlier = time.parse::<u64>().unwrap() - 120;
+    fs::write(&history, format!("{}\n{} {}", header, earlier, rest)).unwrap();
+    assert!(fs::read_to_string(&history).unwrap().ends_with(" 1 too-high 60 400\n"));
+
+    let output = aoc(&stub, &cache, &["submit", "3", "1", "400"]);
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Not submitting 400: it was already rejected as too high\n");
+    let output = aoc(&stub, &cache, &["submit", "3", "1", "401"]);
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Not submitting 401: 400 was too high\n");
+    assert_eq!(stub.requests().len(), 1);
+
+    let output = aoc(&stub, &cache, &["submit", "3", "1", "357"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "357 is correct for day 3 part 1\n");
+    assert!(fs::read_to_string(&history).unwrap().ends_with(" 1 correct - 357\n"));
+
+    let output = aoc(&stub, &cache, &["submit", "3", "1", "358"]);
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Not submitting 358: part 1 is already solved with 357\n");
+    assert_eq!(stub.requests().len(), 2);
+
+    fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn test_submit_blank_answer() {
+    let stub = answer_server();
+    let cache = temp_dir("submit-blank");
+
+    for answer in ["", "  ", "35\n7"] {
+        let output = aoc(&stub, &cache, &["submit", "3", "1", answer]);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("Invalid answer "));
+    }
+    assert!(stub.requests().is_empty());
+    assert!(!cache.join("2025/day-3/submissions").exists());
+
+    fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn test_submit_negative_answer() {
+    let stub = answer_server();
+    let cache = temp_dir("submit-negative");
+
+    let output = aoc(&stub, &cache, &["submit", "3", "1", "-5"]);
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "-5: too low, wait 1m before the next answer\n");
+    assert_eq!(stub.requests()[0].body, "level=1&answer=-5");
+
+    fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn test_submit_wrong_level() {
+    let stub = answer_server();
+    let cache = temp_dir("submit-level");
+
+    let output = aoc(&stub, &cache, &["submit", "3", "2", "10"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "10: not checked, the part is already solved or still locked\n"
+    );
+
+    // not a wrong answer, so it may be posted again
+    aoc(&stub, &cache, &["submit", "3", "2", "10"]);
+    assert_eq!(stub.requests().len(), 2);
+
+    fs::remove_dir_all(&cache).unwrap();
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Command line arguments, split into positional arguments and options.
+///
+/// Options start with `-` or `--`. An option listed in `value_options` consumes the
+/// following argument as its value (`--format json` or `--format=json`), every other
+/// option is a plain flag. A lone `-`, negative numbers like `-5` and every argument
+/// after `--` are kept as positional arguments
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, Option<String>>
+}
+
+impl Args {
+    pub fn parse<I>(args: I, value_options: &[&str]) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>
+    {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                parsed.positional.extend(args);
+                break;
+            }
+
+            let name = match arg.strip_prefix("--").or_else(|| arg.strip_prefix('-')) {
+                Some(name) if !name.is_empty() && !name.starts_with(|c: char| c.is_ascii_digit()) => name,
+                _ => {
+                    parsed.positional.push(arg);
+                    continue;
+                }
+            };
+
+            let (name, value) = match name.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None if value_options.contains(&name) => {
+                    let value = args.next()
+                        .ok_or_else(|| format!("Option --{} requires a value", name))?;
+                    (name.to_string(), Some(value))
+                },
+                None => (name.to_string(), None)
+            };
+            parsed.options.insert(name, value);
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    /// Parses the positional argument at `index`. `what` names the argument in error messages
+    pub fn parse_positional<T: FromStr>(&self, index: usize, what: &str) -> Result<T, String> {
+        let value = self.positional.get(index)
+            .ok_or_else(|| format!("Missing argument <{}>", what))?;
+
+        value.parse().map_err(|_| format!("Invalid {}: {:?}", what, value))
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|v| v.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = Args::parse(args("run 7 --format json -v 2 -"), &["format"]).unwrap();
+
+        assert_eq!(parsed.positional(), &["run", "7", "2", "-"]);
+        assert_eq!(parsed.option("format"), Some("json"));
+        assert!(parsed.flag("v"));
+        assert_eq!(parsed.parse_positional::<u32>(1, "day"), Ok(7));
+        assert!(parsed.parse_positional::<u32>(0, "day").is_err());
+        assert!(parsed.parse_positional::<u32>(4, "day").is_err());
+    }
+
+    #[test]
+    fn test_parse_positional_dashes() {
+        let parsed = Args::parse(args("submit 3 1 -5 -v"), &[]).unwrap();
+        assert_eq!(parsed.positional(), &["submit", "3", "1", "-5"]);
+        assert!(parsed.flag("v"));
+
+        let parsed = Args::parse(args("submit -v 3 1 -- --year -x"), &["year"]).unwrap();
+        assert_eq!(parsed.positional(), &["submit", "3", "1", "--year", "-x"]);
+        assert_eq!(parsed.option("year"), None);
+        assert!(parsed.flag("v"));
+    }
+
+    #[test]
+    fn test_missing_value() {
+        assert!(Args::parse(args("run --format"), &["format"]).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Sub};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, One, Signed};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static HOOK: Once = Once::new();
+
+/// Switches the arithmetic helpers of this module to checked operations.
+///
+/// An overflow then unwinds with an [`Overflow`], which [`catch`] turns into an error.
+/// Without it the helpers use the plain operators, which wrap silently in release builds
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if info.payload().downcast_ref::<Overflow>().is_none() {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f` and returns the overflow it ran into. Other panics are passed on
+pub fn catch<R>(f: impl FnOnce() -> R) -> Result<R, Overflow> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| match payload.downcast::<Overflow>() {
+        Ok(overflow) => *overflow,
+        Err(payload) => panic::resume_unwind(payload)
+    })
+}
+
+/// The place of a checked operation: the solver function and, if known, the input position it works on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Site {
+    pub function: &'static str,
+    /// 1-based
+    pub line: Option<usize>,
+    /// 1-based
+    pub column: Option<usize>
+}
+
+impl Site {
+    pub const fn new(function: &'static str) -> Self {
+        Site { function, line: None, column: None }
+    }
+
+    /// Sets the 0-based index of the input line
+    pub fn on_line(self, line: usize) -> Self {
+        Site { line: Some(line + 1), ..self }
+    }
+
+    /// Sets the 0-based index of the column in the input line
+    pub fn at_column(self, column: usize) -> Self {
+        Site { column: Some(column + 1), ..self }
+    }
+}
+
+impl Display for Site {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.function)?;
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " (input line {}, column {})", line, column),
+            (Some(line), None) => write!(f, " (input line {})", line),
+            _ => Ok(())
+        }
+    }
+}
+
+/// An arithmetic overflow in checked mode
+#[derive(Clone, Debug, PartialEq)]
+pub struct Overflow {
+    pub site: Site,
+    pub operation: String
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Overflow in {}: {}", self.site, self.operation)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+fn overflow(site: &Site, operation: String) -> ! {
+    panic::panic_any(Overflow { site: *site, operation })
+}
+
+pub fn add<T>(a: T, b: T, site: &Site) -> T
+where
+    T: Add<Output = T> + CheckedAdd + Copy + Display
+{
+    if !is_enabled() {
+        return a + b;
+    }
+    a.checked_add(&b).unwrap_or_else(|| overflow(site, format!("{} + {}", a, b)))
+}
+
+pub fn sub<T>(a: T, b: T, site: &Site) -> T
+where
+    T: Sub<Output = T> + CheckedSub + Copy + Display
+{
+    if !is_enabled() {
+        return a - b;
+    }
+    a.checked_sub(&b).unwrap_or_else(|| overflow(site, format!("{} - {}", a, b)))
+}
+
+pub fn mul<T>(a: T, b: T, site: &Site) -> T
+where
+    T: Mul<Output = T> + CheckedMul + Copy + Display
+{
+    if !is_enabled() {
+        return a * b;
+    }
+    a.checked_mul(&b).unwrap_or_else(|| overflow(site, format!("{} * {}", a, b)))
+}
+
+pub fn pow<T>(base: T, exp: usize, site: &Site) -> T
+where
+    T: Mul<Output = T> + CheckedMul + One + Copy + Display
+{
+    if !is_enabled() {
+        return num_traits::pow(base, exp);
+    }
+    num_traits::checked_pow(base, exp).unwrap_or_else(|| overflow(site, format!("{}^{}", base, exp)))
+}
+
+/// Absolute value, which overflows for the smallest value of a type
+pub fn abs<T>(a: T, site: &Site) -> T
+where
+    T: Signed + CheckedNeg + Copy + Display
+{
+    if !is_enabled() || !a.is_negative() {
+        return a.abs();
+    }
+    a.checked_neg().unwrap_or_else(|| overflow(site, format!("|{}|", a)))
+}
+
+/// Sums with [`add`]
+pub fn sum<T, I>(values: I, site: &Site) -> T
+where
+    T: Add<Output = T> + CheckedAdd + Copy + Display + Default,
+    I: IntoIterator<Item = T>
+{
+    values.into_iter().fold(T::default(), |total, v| add(total, v, site))
+}
+
+/// Multiplies with [`mul`]
+pub fn product<T, I>(values: I, site: &Site) -> T
+where
+    T: Mul<Output = T> + CheckedMul + One + Copy + Display,
+    I: IntoIterator<Item = T>
+{
+    values.into_iter().fold(T::one(), |total, v| mul(total, v, site))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow() {
+        enable();
+        let site = Site::new("day_3::puzzle_2::joltage").on_line(3);
+
+        assert_eq!(mul(3u64, 4, &site), 12);
+        assert_eq!(pow(10u64, 12, &site), 1_000_000_000_000);
+
+        let overflow = catch(|| pow(10u64, 20, &site)).unwrap_err();
+        assert_eq!(overflow.to_string(), "Overflow in day_3::puzzle_2::joltage (input line 4): 10^20");
+
+        assert_eq!(abs(-7i64, &site), 7);
+        let overflow = catch(|| abs(i64::MIN, &site)).unwrap_err();
+        assert_eq!(overflow.operation, format!("|{}|", i64::MIN));
+
+        let overflow = catch(|| product([i64::MAX, 2], &Site::new("value").on_line(0).at_column(6))).unwrap_err();
+        assert_eq!(overflow.to_string(), format!("Overflow in value (input line 1, column 7): {} * 2", i64::MAX));
+    }
+}
@@ -0,0 +1,104 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Invalid puzzle input, located by line and column.
+///
+/// Parsers of single lines only know the column. The line is added by the caller
+/// with [`ParseError::on_line`] and the file by the runner with [`ParseError::in_file`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// The offending part of the input
+    pub text: String,
+    pub file: Option<String>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize
+}
+
+impl ParseError {
+    /// `column` is the zero based character index of `text` in its line
+    pub fn new(message: &str, text: &str, column: usize) -> Self {
+        ParseError {
+            message: message.to_string(),
+            text: text.to_string(),
+            file: None,
+            line: 1,
+            column: column + 1
+        }
+    }
+
+    /// Places the error on the line with the zero based `line_index`
+    pub fn on_line(mut self, line_index: usize) -> Self {
+        self.line = line_index + 1;
+        self
+    }
+
+    /// Moves the error to the right, for text which was cut out of a longer line
+    pub fn shifted(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        write!(f, "{}:{}:{}: {}: {:?}", file, self.line, self.column, self.message, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a number which starts at the zero based character index `column`
+pub fn parse_number<T: FromStr>(text: &str, column: usize) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::new("Expected a number", text, column))
+}
+
+/// Splits `text` at `separator` and returns every field together with its zero based column
+pub fn split_columns(text: &str, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut column = 0;
+
+    text.split(separator).map(move |field| {
+        let field_column = column;
+        column += field.chars().count() + 1;
+
+        (field_column, field)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let e = ParseError::new("Unknown direction", "X", 0)
+            .shifted(4)
+            .on_line(2)
+            .in_file("input");
+
+        assert_eq!((e.line, e.column), (3, 5));
+        assert_eq!(e.to_string(), "input:3:5: Unknown direction: \"X\"");
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number::<u32>("68", 1), Ok(68));
+        assert_eq!(parse_number::<u32>("6x", 1).unwrap_err().column, 2);
+    }
+
+    #[test]
+    fn test_split_columns() {
+        let fields: Vec<(usize, &str)> = split_columns("162,817,812", ',').collect();
+
+        assert_eq!(fields, vec![(0, "162"), (4, "817"), (8, "812")]);
+    }
+}
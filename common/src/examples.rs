@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::Arc;
+use libtest_mimic::{Arguments, Failed, Trial};
+use serde::Deserialize;
+use crate::input::split_lines;
+use crate::output::format_parse_error;
+use crate::params::Params;
+use crate::registry::{erase, Day};
+use crate::{Answer, Solution};
+
+/// Name of the manifest in the directory of a day
+pub const MANIFEST: &str = "examples.toml";
+
+/// The examples of a day, read from its `examples.toml`:
+///
+/// ```toml
+/// [[example]]
+/// input = "test-input"
+/// part1 = 40
+/// part2 = 25272
+/// params = { max_steps = 10 }
+/// ```
+///
+/// Answers which do not fit into a TOML integer are given as strings
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    /// Shown in the test names, defaults to the input file
+    pub name: Option<String>,
+    /// Path of the input, relative to the directory of the day
+    pub input: String,
+    pub part1: Option<toml::Value>,
+    pub part2: Option<toml::Value>,
+    #[serde(default)]
+    pub params: BTreeMap<String, toml::Value>
+}
+
+impl Manifest {
+    pub fn from(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        Manifest::from(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+impl Example {
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.input)
+    }
+
+    /// Expected answer of a part, `None` if the example does not cover the part
+    pub fn answer(&self, part: u32) -> Option<String> {
+        let answer = match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None
+        };
+
+        answer.map(value_to_string)
+    }
+
+    pub fn params(&self) -> Params {
+        self.params.iter()
+            .fold(Params::new(), |params, (name, value)| params.with(name, &value_to_string(value)))
+    }
+
+    /// Solves `part` for the example and compares the result with the expected answer.
+    /// The input is looked up in `dir`, the directory of the day
+    pub fn check(&self, dir: &Path, day: &dyn Day, part: u32) -> Result<Answer, String> {
+        let expected = self.answer(part)
+            .ok_or_else(|| format!("Example {} has no answer for part {}", self.name(), part))?;
+        let content = fs::read_to_string(dir.join(&self.input))
+            .map_err(|e| format!("Could not read {}: {}", self.input, e))?;
+        let lines = split_lines(&content, day.input_mode());
+
+        let input = day.parse_with(&lines, &self.params())
+            .map_err(|e| format_parse_error(&e.in_file(&self.input), &lines))?;
+        let answer = day.solve(part, input.as_ref())
+            .ok_or_else(|| format!("Part {} is not solved", part))?;
+
+        if answer.to_string() == expected {
+            Ok(answer)
+        } else {
+            Err(format!("Expected {}, got {}", expected, answer))
+        }
+    }
+}
+
+fn value_to_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string()
+    }
+}
+
+/// Entry point of the `examples` test of a day: one test per example and part listed in `examples.toml`
+pub fn test_main<S>() -> ExitCode
+where
+    S: Solution + 'static,
+    S::Input: 'static
+{
+    let args = Arguments::from_args();
+    let manifest = match Manifest::load(Path::new(MANIFEST)) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut trials: Vec<Trial> = vec![];
+    for example in manifest.examples {
+        let example = Arc::new(example);
+
+        for part in [1, 2] {
+            if example.answer(part).is_none() {
+                continue;
+            }
+            let name = format!("{} part {}", example.name(), part);
+            let example = example.clone();
+            trials.push(Trial::test(name, move || {
+                example.check(Path::new("."), erase::<S>().as_ref(), part)
+                    .map(|_| ())
+                    .map_err(Failed::from)
+            }));
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit_code()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        let manifest = Manifest::from(
+            "[[example]]\ninput = \"test-input\"\npart1 = 40\npart2 = \"25272\"\nparams = { max_steps = 10 }\n\n\
+             [[example]]\nname = \"small\"\ninput = \"small-input\"\npart2 = 3\n"
+        ).unwrap();
+
+        let first = &manifest.examples[0];
+        assert_eq!(first.name(), "test-input");
+        assert_eq!(first.answer(1), Some(String::from("40")));
+        assert_eq!(first.answer(2), Some(String::from("25272")));
+        assert_eq!(first.params(), Params::new().with("max_steps", "10"));
+
+        let second = &manifest.examples[1];
+        assert_eq!(second.name(), "small");
+        assert_eq!(second.answer(1), None);
+        assert!(second.params().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        assert!(Manifest::from("[[example]]\ninput = \"test-input\"\npart3 = 1\n").is_err());
+    }
+}
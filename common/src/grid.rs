@@ -0,0 +1,181 @@
+use std::ops::{Index, IndexMut};
+use crate::ParseError;
+
+/// `(row, column)` of a cell. Signed, so neighbours may be computed past the edge and checked with [`Grid::contains`]
+pub type Position = (i64, i64);
+
+const OFFSETS_4: [Position; 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [Position; 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1), (0, 1),
+    (1, -1), (1, 0), (1, 1)
+];
+
+/// Rectangular map of typed cells, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map. `cell` turns a symbol into a cell, `None` rejects it as unknown.
+    /// All lines must have the same length
+    pub fn parse<F>(lines: &[String], cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>
+    {
+        let columns = lines.first().map(|line| line.chars().count()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(lines.len() * columns);
+
+        for (row, line) in lines.iter().enumerate() {
+            let width = line.chars().count();
+            if width != columns {
+                let message = format!("Expected {} columns, found {}", columns, width);
+                return Err(ParseError::new(&message, line, 0).on_line(row));
+            }
+
+            for (column, symbol) in line.chars().enumerate() {
+                let value = cell(symbol)
+                    .ok_or_else(|| ParseError::new("Unknown symbol", &symbol.to_string(), column).on_line(row))?;
+                cells.push(value);
+            }
+        }
+
+        Ok(Grid { cells, rows: lines.len(), columns })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        (0..self.rows as i64).contains(&row) && (0..self.columns as i64).contains(&column)
+    }
+
+    /// The cell at `position`, `None` outside of the grid
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let columns = self.columns as i64;
+        (0..self.rows as i64).flat_map(move |row| (0..columns).map(move |column| (row, column)))
+    }
+
+    /// All cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The cells of a row, left to right
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// The cells of a column, top to bottom
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.columns, "Column {} is outside of a grid with {} columns", column, self.columns);
+        self.cells.iter().skip(column).step_by(self.columns)
+    }
+
+    /// The horizontal and vertical neighbours inside the grid
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &OFFSETS_4)
+    }
+
+    /// The horizontal, vertical and diagonal neighbours inside the grid
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &OFFSETS_8)
+    }
+
+    fn neighbours<'a>(&'a self, (row, column): Position, offsets: &'static [Position]) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter()
+            .map(move |(d_row, d_column)| (row + d_row, column + d_column))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position).then(|| position.0 as usize * self.columns + position.1 as usize)
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Position of the first cell equal to `value`, row by row
+    pub fn find(&self, value: &T) -> Option<Position> {
+        self.iter().find(|(_, cell)| *cell == value).map(|(position, _)| position)
+    }
+
+    /// Positions of all cells equal to `value`, row by row
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(position, _)| position)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position).unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse(&lines("..S\n.^.\n^.."), Some).unwrap();
+
+        assert_eq!((grid.rows(), grid.columns()), (3, 3));
+        assert_eq!(grid.find(&'S'), Some((0, 2)));
+        assert_eq!(grid.find_all(&'^').collect::<Vec<_>>(), vec![(1, 1), (2, 0)]);
+        assert_eq!(grid[(1, 1)], '^');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.row(1), &['.', '^', '.']);
+        assert_eq!(grid.column(0).collect::<String>(), "..^");
+
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Grid::parse(&lines("@.\n.x"), |c| (c != 'x').then_some(c)).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+
+        let e = Grid::parse(&lines("@.\n."), Some).unwrap_err();
+        assert_eq!((e.line, e.message.as_str()), (2, "Expected 2 columns, found 1"));
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::parse(&lines("@@\n@."), |c| Some(c == '@')).unwrap();
+        grid[(0, 1)] = false;
+
+        assert_eq!(grid.find_all(&true).collect::<Vec<_>>(), vec![(0, 0), (1, 0)]);
+    }
+}
@@ -0,0 +1,27 @@
+/// Stable fingerprint of a puzzle input, used to tell inputs apart without storing them.
+///
+/// Windows line endings and trailing line breaks do not change the hash
+pub fn input_hash(content: &str) -> String {
+    let content = content.replace("\r\n", "\n");
+
+    format!("{:016x}", fnv1a(content.trim_end_matches('\n').as_bytes()))
+}
+
+/// 64 bit FNV-1a hash
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(input_hash("L68\nL30\n"), input_hash("L68\r\nL30"));
+    }
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::io::{self, Read};
+use crate::warnings::warn;
+
+/// Input name which stands for standard input
+pub const STDIN: &str = "-";
+
+/// How a puzzle input is split into lines
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputMode {
+    /// Leading and trailing whitespace of the whole file is removed
+    #[default]
+    Trimmed,
+    /// Every character is kept, only the line breaks at the end of the file are dropped.
+    /// All lines are padded with spaces to the length of the longest line,
+    /// so fixed-width layouts can be read column by column
+    Preserved
+}
+
+/// Reads a puzzle input and splits it into lines.
+/// Leading and trailing whitespace of the whole file is removed
+pub fn read_file(file_name: &str) -> Vec<String> {
+    read_file_with(file_name, InputMode::Trimmed)
+}
+
+pub fn read_file_with(file_name: &str, mode: InputMode) -> Vec<String> {
+    let content = fs::read_to_string(file_name)
+        .unwrap_or_else(|e| panic!("Could not read file {}: {}", file_name, e));
+
+    split_lines(&content, mode)
+}
+
+/// Reads a whole input, `-` reads standard input
+pub fn read_source(name: &str) -> io::Result<String> {
+    if name != STDIN {
+        return fs::read_to_string(name);
+    }
+
+    let mut content = String::new();
+    io::stdin().read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// Splits an input into lines. Windows line endings are accepted in both modes
+pub fn split_lines(content: &str, mode: InputMode) -> Vec<String> {
+    if content.contains("\r\n") {
+        warn("Windows line endings were converted");
+    }
+    let content = content.replace("\r\n", "\n");
+
+    match mode {
+        InputMode::Trimmed => content
+            .trim()
+            .split('\n')
+            .map(String::from)
+            .collect(),
+        InputMode::Preserved => {
+            let lines: Vec<&str> = content.trim_end_matches('\n').split('\n').collect();
+            let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+            let padded = lines.iter().filter(|l| l.chars().count() < width).count();
+            if padded > 0 {
+                warn(format!("{} line(s) were padded with spaces to {} columns", padded, width));
+            }
+
+            // not with a format width, which is limited to u16
+            lines.iter()
+                .map(|l| l.to_string() + &" ".repeat(width - l.chars().count()))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_lines() {
+        let lines = split_lines("\nL68\r\nL30\n\n", InputMode::Trimmed);
+
+        assert_eq!(lines, vec!["L68", "L30"]);
+    }
+
+    #[test]
+    fn test_split_lines_preserved() {
+        let lines = split_lines("  1 2\r\n 34\r\n*  +  \n\n", InputMode::Preserved);
+
+        assert_eq!(lines, vec!["  1 2 ", " 34   ", "*  +  "]);
+    }
+
+    #[test]
+    fn test_split_lines_preserved_long() {
+        let long = "1 ".repeat(40_000);
+        let lines = split_lines(&format!("{}\n+", long), InputMode::Preserved);
+
+        assert_eq!(lines[1].len(), long.len());
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt;
+use std::str::FromStr;
+use num_traits::PrimInt;
+use crate::error::{parse_number, split_columns};
+use crate::ParseError;
+
+/// Range of integers, both ends included
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T = i64> {
+    pub start: T,
+    pub end: T
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "Interval ends before it starts");
+        Interval { start, end }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Number of values in the interval
+    pub fn len(&self) -> T {
+        self.end - self.start + T::one()
+    }
+
+    /// Whether the intervals overlap or touch, so their union is one interval
+    fn joins(&self, other: &Self) -> bool {
+        let touches = |a: &Self, b: &Self| a.end.checked_add(&T::one()).is_none_or(|after| b.start <= after);
+        touches(self, other) && touches(other, self)
+    }
+}
+
+impl<T: PrimInt + FromStr> Interval<T> {
+    /// Parses `start-end`, e.g. `11-22`. Negative numbers are not supported
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let parts: Vec<(usize, &str)> = split_columns(text, '-').collect();
+        if parts.len() != 2 {
+            return Err(ParseError::new("Expected a range like 3-5", text, 0));
+        }
+        let start: T = parse_number(parts[0].1, parts[0].0)?;
+        let end: T = parse_number(parts[1].1, parts[1].0)?;
+        if end < start {
+            return Err(ParseError::new("Range ends before it starts", text, 0));
+        }
+
+        Ok(Interval { start, end })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as sorted intervals. Overlapping and adjacent intervals are merged,
+/// so every set has exactly one representation
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T = i64> {
+    intervals: Vec<Interval<T>>
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// Sorts and merges arbitrary intervals
+    fn normalized(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.sort();
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if last.joins(&interval) => last.end = last.end.max(interval.end),
+                _ => merged.push(interval)
+            }
+        }
+
+        IntervalSet { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = IntervalSet::normalized(intervals);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        IntervalSet::normalized([&self.intervals[..], &other.intervals[..]].concat())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start <= end {
+                intervals.push(Interval { start, end });
+            }
+
+            // the interval which ends first cannot overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The values of `self` which are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut j = 0;
+
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+
+            while let (Some(current), Some(cut)) = (rest, other.intervals.get(j)) {
+                if cut.end < current.start {
+                    j += 1;
+                    continue;
+                }
+                if cut.start > current.end {
+                    break;
+                }
+
+                if cut.start > current.start {
+                    intervals.push(Interval { start: current.start, end: cut.start - T::one() });
+                }
+                if cut.end < current.end {
+                    rest = Some(Interval { start: cut.end + T::one(), end: current.end });
+                    j += 1;
+                } else {
+                    rest = None;
+                }
+            }
+
+            intervals.extend(rest);
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// Binary search for the interval which could contain `value`
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end < value);
+        self.intervals.get(index).is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Whether every value of `interval` is in the set
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        let index = self.intervals.partition_point(|i| i.end < interval.start);
+        self.intervals.get(index).is_some_and(|i| i.start <= interval.start && interval.end <= i.end)
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::zero(), |len, interval| len + interval.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The merged intervals in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// The intervals between the intervals of the set, in ascending order
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.windows(2).map(|pair| Interval { start: pair[0].end + T::one(), end: pair[1].start - T::one() })
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        IntervalSet::normalized(intervals.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn test_normalize() {
+        let s = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (6, 6)]);
+
+        assert_eq!(s, set(&[(3, 6), (10, 20)]));
+        assert_eq!(s.len(), 15);
+        assert_eq!(s.gaps().collect::<Vec<_>>(), vec![Interval::new(7, 9)]);
+        assert_eq!(IntervalSet::from_iter([Interval::new(1, u8::MAX), Interval::new(3, 4)]).len(), u8::MAX);
+    }
+
+    #[test]
+    fn test_membership() {
+        let s = set(&[(3, 5), (10, 20)]);
+
+        assert!(s.contains(3) && s.contains(5) && s.contains(17));
+        assert!(!s.contains(1) && !s.contains(8) && !s.contains(21));
+        assert!(s.contains_interval(&Interval::new(11, 20)));
+        assert!(!s.contains_interval(&Interval::new(4, 10)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(5, 22), (28, 40)]);
+
+        assert_eq!(a.union(&b), set(&[(1, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 22), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(1, 4), (23, 27)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (31, 40)]));
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = IntervalSet::new();
+        c.insert(Interval::new(1, 2));
+        c.insert(Interval::new(3, 3));
+        assert_eq!(c, set(&[(1, 3)]));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Interval::<u64>::parse("11-22"), Ok(Interval::new(11, 22)));
+
+        let e = Interval::<u64>::parse("11-x").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (4, "x"));
+        assert!(Interval::<u64>::parse("22-11").is_err());
+        assert!(Interval::<u64>::parse("22").is_err());
+    }
+}
@@ -0,0 +1,32 @@
+pub mod answer;
+pub mod args;
+pub mod checked;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod hash;
+pub mod input;
+pub mod interval;
+pub mod log;
+pub mod memo;
+pub mod output;
+pub mod params;
+pub mod point;
+pub mod registry;
+pub mod solution;
+pub mod union_find;
+pub mod warnings;
+
+pub use answer::Answer;
+pub use args::Args;
+pub use error::ParseError;
+pub use grid::Grid;
+pub use input::{read_file, read_file_with, InputMode};
+pub use interval::{Interval, IntervalSet};
+pub use memo::Memo;
+pub use output::{print_parse_error, print_result};
+pub use params::Params;
+pub use point::{Point2, Point3};
+pub use registry::Registry;
+pub use solution::Solution;
+pub use union_find::UnionFind;
@@ -0,0 +1,85 @@
+use std::fmt::Arguments;
+use std::sync::atomic::{AtomicU8, Ordering};
+use crate::Args;
+
+/// Verbosity of the solver traces, which go to stderr
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// Progress of a solver, e.g. one line per round
+    Debug,
+    /// Every step of a solver
+    Trace
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+impl Level {
+    /// `-v` enables [`Level::Debug`], `-vv` or `--trace` enables [`Level::Trace`]
+    pub fn from(args: &Args) -> Self {
+        if args.flag("trace") || args.flag("vv") {
+            Level::Trace
+        } else if args.flag("v") {
+            Level::Debug
+        } else {
+            Level::Off
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Level::Off => "off",
+            Level::Debug => "debug",
+            Level::Trace => "trace"
+        }
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Used by [`debug!`](crate::debug) and [`trace!`](crate::trace), which check the level before formatting
+pub fn write(level: Level, module: &str, message: Arguments) {
+    eprintln!("[{} {}] {}", level.name(), module, message);
+}
+
+/// Writes a message to stderr if `-v` is given
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// Writes a message to stderr if `-vv` or `--trace` is given
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        let args = |a: &[&str]| Args::parse(a.iter().map(|s| s.to_string()), &[]).unwrap();
+
+        assert_eq!(Level::from(&args(&["run"])), Level::Off);
+        assert_eq!(Level::from(&args(&["run", "-v"])), Level::Debug);
+        assert_eq!(Level::from(&args(&["-vv"])), Level::Trace);
+        assert_eq!(Level::from(&args(&["--trace"])), Level::Trace);
+        assert!(Level::Debug < Level::Trace);
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+
+/// Cache for recursive solvers, e.g. counting paths through a DAG. Each key is computed once.
+///
+/// Recursive functions take the memo as parameter and wrap their body in [`Memo::get_or_compute`].
+/// Deep recursions which would overflow the stack use [`Memo::evaluate`] instead
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize
+}
+
+/// Usage of a [`Memo`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo { cache: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    /// The cached value of `key`, otherwise the result of `compute`, which may recurse through the memo
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Computes `key` without recursion. `dependencies` lists the keys a key is computed from,
+    /// `combine` computes a key from the values of its dependencies, in the same order.
+    ///
+    /// Panics if the dependencies contain a cycle
+    pub fn evaluate<D, C>(&mut self, key: K, dependencies: D, combine: C) -> V
+    where
+        D: Fn(&K) -> Vec<K>,
+        C: Fn(&K, &[V]) -> V
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        let mut in_progress: HashSet<K> = HashSet::new();
+        // every key is visited twice: first to push its dependencies, then to combine them
+        let mut stack: Vec<(K, Option<Vec<K>>)> = vec![(key.clone(), None)];
+
+        while let Some((current, visited)) = stack.pop() {
+            match visited {
+                None => {
+                    if self.cache.contains_key(&current) {
+                        self.hits += 1;
+                        continue;
+                    }
+                    assert!(in_progress.insert(current.clone()), "Dependencies of the memo contain a cycle");
+
+                    let dependencies = dependencies(&current);
+                    let open: Vec<K> = dependencies.iter().filter(|d| !self.cache.contains_key(d)).cloned().collect();
+                    stack.push((current, Some(dependencies)));
+                    stack.extend(open.into_iter().map(|d| (d, None)));
+                },
+                Some(dependencies) => {
+                    let values: Vec<V> = dependencies.iter().map(|d| self.cache[d].clone()).collect();
+                    self.misses += 1;
+                    self.cache.insert(current.clone(), combine(&current, &values));
+                    in_progress.remove(&current);
+                }
+            }
+        }
+
+        self.cache[&key].clone()
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats { hits: self.hits, misses: self.misses, entries: self.cache.len() }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} hit(s), {} miss(es), {} entries", self.hits, self.misses, self.entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        memo.get_or_compute(n, |memo| match n {
+            0 | 1 => n,
+            n => fibonacci(n - 1, memo) + fibonacci(n - 2, memo)
+        })
+    }
+
+    #[test]
+    fn test_get_or_compute() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(50, &mut memo), 12586269025);
+        assert_eq!(memo.stats(), Stats { hits: 48, misses: 51, entries: 51 });
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut memo: Memo<u64, u64> = Memo::new();
+        let dependencies = |&n: &u64| if n < 2 { vec![] } else { vec![n - 1, n - 2] };
+        let combine = |&n: &u64, values: &[u64]| if n < 2 { n } else { values[0].wrapping_add(values[1]) };
+
+        // far deeper than the stack would allow for the recursive version
+        memo.evaluate(100_000, dependencies, combine);
+        assert_eq!(memo.evaluate(50, dependencies, combine), 12586269025);
+        assert_eq!(memo.stats().entries, 100_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle")]
+    fn test_evaluate_cycle() {
+        let mut memo: Memo<u64, u64> = Memo::new();
+        memo.evaluate(0, |&n| vec![(n + 1) % 3], |_, values| values[0]);
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use crate::error::ParseError;
+
+pub fn print_result<T: Display>(result: T) {
+    println!("Result is {}", result);
+}
+
+/// Prints a parse error together with the offending input line
+pub fn print_parse_error(error: &ParseError, lines: &[String]) {
+    eprint!("{}", format_parse_error(error, lines));
+}
+
+pub fn format_parse_error(error: &ParseError, lines: &[String]) -> String {
+    let file = error.file.as_deref().unwrap_or("<input>");
+    let mut report = format!("error: {}\n --> {}:{}:{}\n", error.message, file, error.line, error.column);
+
+    if let Some(line) = lines.get(error.line - 1) {
+        let number = error.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(error.column - 1);
+        let marker = "^".repeat(error.text.chars().count().max(1));
+
+        report.push_str(&format!("{} |\n{} | {}\n{} | {}{}\n", gutter, number, line, gutter, indent, marker));
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_parse_error() {
+        let lines = vec![String::from("L68"), String::from("X30")];
+        let error = ParseError::new("Unknown direction", "X", 0).on_line(1).in_file("input");
+
+        let expected = "error: Unknown direction\n --> input:2:1\n  |\n2 | X30\n  | ^\n";
+        assert_eq!(format_parse_error(&error, &lines), expected);
+    }
+}
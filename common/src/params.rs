@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Named settings of a puzzle which differ between the examples and the real input,
+/// e.g. the number of steps to simulate
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, String>
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn with(mut self, name: &str, value: &str) -> Self {
+        self.values.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Returns the parameter `name` or `default` if it is not set.
+    /// Panics if the value cannot be parsed, parameters are only given by the example manifests
+    pub fn get_or<T>(&self, name: &str, default: T) -> T
+    where
+        T: FromStr,
+        T::Err: Debug
+    {
+        match self.values.get(name) {
+            Some(value) => value.parse()
+                .unwrap_or_else(|e| panic!("Invalid value {:?} for parameter {}: {:?}", value, name, e)),
+            None => default
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_or() {
+        let params = Params::new().with("max_steps", "10");
+
+        assert_eq!(params.get_or("max_steps", 1000), 10);
+        assert_eq!(params.get_or("width", 7), 7);
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use num_traits::{Num, ToPrimitive};
+use crate::error::{parse_number, split_columns};
+use crate::ParseError;
+
+/// Smallest axis-aligned box which contains a set of points, both corners included
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundingBox<P> {
+    pub min: P,
+    pub max: P
+}
+
+/// Defines a point type with the given coordinates. Points double as vectors: they can be added, subtracted,
+/// negated and scaled
+macro_rules! point {
+    ($name:ident, $count:literal, $count_word:literal, $($field:ident),+) => {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name<T = i64> {
+            $(pub $field: T),+
+        }
+
+        impl<T> $name<T> {
+            pub const fn new($($field: T),+) -> Self {
+                $name { $($field),+ }
+            }
+        }
+
+        impl<T: FromStr> $name<T> {
+            #[doc = concat!("Parses ", $count_word, " comma separated coordinates, errors point at the offending column")]
+            pub fn parse(text: &str) -> Result<Self, ParseError> {
+                let fields: Vec<(usize, &str)> = split_columns(text, ',').collect();
+                if fields.len() != $count {
+                    return Err(ParseError::new(concat!("Expected ", $count_word, " coordinates"), text, 0));
+                }
+                let mut fields = fields.into_iter();
+                $(
+                    let (column, value) = fields.next().unwrap();
+                    let $field = parse_number(value, column)?;
+                )+
+
+                Ok($name { $($field),+ })
+            }
+        }
+
+        impl<T: Copy + PartialOrd + Num> $name<T> {
+            /// Sum of the distances along each axis
+            pub fn manhattan(&self, other: &Self) -> T {
+                T::zero() $(+ abs_diff(self.$field, other.$field))+
+            }
+
+            /// Largest distance along any axis
+            pub fn chebyshev(&self, other: &Self) -> T {
+                let mut distance = T::zero();
+                $(
+                    let d = abs_diff(self.$field, other.$field);
+                    if d > distance {
+                        distance = d;
+                    }
+                )+
+                distance
+            }
+
+            /// Square of the Euclidean distance, exact for integer coordinates
+            pub fn squared_euclidean(&self, other: &Self) -> T {
+                T::zero() $(+ abs_diff(self.$field, other.$field) * abs_diff(self.$field, other.$field))+
+            }
+        }
+
+        impl<T: Copy + PartialOrd + Num + ToPrimitive> $name<T> {
+            /// Euclidean distance, computed in `f64` so large coordinates do not overflow
+            pub fn euclidean(&self, other: &Self) -> f64 {
+                let squares = 0.0 $(+ abs_diff(self.$field, other.$field).to_f64().unwrap_or(f64::NAN).powi(2))+;
+                squares.sqrt()
+            }
+        }
+
+        impl<T: Copy + Ord> $name<T> {
+            /// `None` if there are no points
+            pub fn bounding_box<I: IntoIterator<Item = Self>>(points: I) -> Option<BoundingBox<Self>> {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+
+                Some(points.fold(BoundingBox { min: first, max: first }, |bounds, p| BoundingBox {
+                    min: $name { $($field: bounds.min.$field.min(p.$field)),+ },
+                    max: $name { $($field: bounds.max.$field.max(p.$field)),+ }
+                }))
+            }
+        }
+
+        impl<T: PartialOrd> BoundingBox<$name<T>> {
+            pub fn contains(&self, point: &$name<T>) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $name { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $name { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $name { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $name { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        /// Written like the input, e.g. `3,-4`
+        impl<T: fmt::Display> fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coordinates = [$(self.$field.to_string()),+];
+                f.write_str(&coordinates.join(","))
+            }
+        }
+    };
+}
+
+point!(Point2, 2, "two", x, y);
+point!(Point3, 3, "three", x, y, z);
+
+/// Distance of two numbers, also for unsigned types
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.squared_euclidean(&b), 25);
+        assert_eq!(a.euclidean(&b), 5.0);
+
+        let c: Point3<u32> = Point3::new(5, 0, 2);
+        assert_eq!(c.manhattan(&Point3::new(2, 1, 2)), 4);
+        assert_eq!(c.chebyshev(&Point3::new(2, 1, 2)), 3);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point3::new(1, 2, 3) + Point3::new(1, 1, 1) * 2;
+        assert_eq!(p, Point3::new(3, 4, 5));
+
+        p -= Point3::new(3, 4, 5);
+        assert_eq!(p, Point3::default());
+        assert_eq!(-Point2::new(1, -1), Point2::new(-1, 1));
+        assert_eq!(Point2::new(7, 3) - Point2::new(2, 5), Point2::new(5, -2));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Point3::parse("162,817,812"), Ok(Point3::new(162, 817, 812)));
+        assert_eq!(Point2::<i64>::parse("7,-1").unwrap().to_string(), "7,-1");
+
+        let e = Point2::<i64>::parse("7,x").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (3, "x"));
+        let e = Point3::<i64>::parse("1,2").unwrap_err();
+        assert_eq!(e.message, "Expected three coordinates");
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point2::new(7, 1), Point2::new(2, 5), Point2::new(11, 3)];
+        let bounds = Point2::bounding_box(points).unwrap();
+
+        assert_eq!(bounds, BoundingBox { min: Point2::new(2, 1), max: Point2::new(11, 5) });
+        assert!(bounds.contains(&Point2::new(2, 5)));
+        assert!(!bounds.contains(&Point2::new(12, 5)));
+        assert_eq!(Point2::<i64>::bounding_box([]), None);
+    }
+}
@@ -0,0 +1,140 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::InputMode;
+use crate::params::Params;
+use crate::Solution;
+
+/// Type erased view on a [`Solution`], so days with different input types can live in one registry
+pub trait Day: Send + Sync {
+    fn input_mode(&self) -> InputMode;
+
+    fn parse(&self, lines: &[String]) -> Result<Box<dyn Any>, ParseError> {
+        self.parse_with(lines, &Params::new())
+    }
+
+    fn parse_with(&self, lines: &[String], params: &Params) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solves `part` for an input returned by [`Day::parse`].
+    /// Returns `None` for unknown or unsolved parts
+    fn solve(&self, part: u32, input: &dyn Any) -> Option<Answer>;
+
+    fn run(&self, part: u32, lines: &[String]) -> Result<Option<Answer>, ParseError> {
+        let input = self.parse(lines)?;
+        Ok(self.solve(part, input.as_ref()))
+    }
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+/// Wraps a [`Solution`] as [`Day`]
+pub fn erase<S>() -> Box<dyn Day>
+where
+    S: Solution + 'static,
+    S::Input: 'static
+{
+    Box::new(Erased::<S>(PhantomData))
+}
+
+impl<S> Day for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static
+{
+    fn input_mode(&self) -> InputMode {
+        S::INPUT_MODE
+    }
+
+    fn parse_with(&self, lines: &[String], params: &Params) -> Result<Box<dyn Any>, ParseError> {
+        let input = S::parse_with(lines, params)?;
+        Ok(Box::new(input))
+    }
+
+    fn solve(&self, part: u32, input: &dyn Any) -> Option<Answer> {
+        let input = input.downcast_ref::<S::Input>()
+            .expect("Input was not parsed by the same day");
+
+        match part {
+            1 => Some(S::part1(input)),
+            2 => S::part2(input),
+            _ => None
+        }
+    }
+}
+
+/// All known solutions, keyed by day
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u32, Box<dyn Day>>
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    pub fn register<S>(&mut self, day: u32)
+    where
+        S: Solution + 'static,
+        S::Input: 'static
+    {
+        let previous = self.days.insert(day, erase::<S>());
+        if previous.is_some() {
+            panic!("Day {} is registered twice", day);
+        }
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn Day> {
+        self.days.get(&day).map(|d| d.as_ref())
+    }
+
+    /// Registered days in ascending order
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.days.keys().copied()
+    }
+
+    /// Parses the input and solves the part. Returns `Ok(None)` for unknown days and parts
+    pub fn run(&self, day: u32, part: u32, lines: &[String]) -> Result<Option<Answer>, ParseError> {
+        match self.get(day) {
+            Some(solution) => solution.run(part, lines),
+            None => Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        type Input = usize;
+
+        fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+            Ok(lines.len())
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            (*input).into()
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Answer> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+        registry.register::<LineCount>(3);
+        let lines = vec![String::from("a"), String::from("b")];
+
+        assert_eq!(registry.days().collect::<Vec<u32>>(), vec![3]);
+        assert_eq!(registry.run(3, 1, &lines), Ok(Some(Answer::Int(2))));
+        assert_eq!(registry.run(3, 2, &lines), Ok(None));
+        assert_eq!(registry.run(4, 1, &lines), Ok(None));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::InputMode;
+use crate::params::Params;
+
+/// A solved puzzle day.
+///
+/// The input is parsed once and shared by both parts
+pub trait Solution {
+    type Input;
+
+    /// How the input file is split into the lines passed to [`Solution::parse`]
+    const INPUT_MODE: InputMode = InputMode::Trimmed;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError>;
+
+    /// Parses an input which needs other [`Params`] than the real input, e.g. an example.
+    /// Only days with parameters have to implement it
+    fn parse_with(lines: &[String], _params: &Params) -> Result<Self::Input, ParseError> {
+        Self::parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    /// Returns `None` as long as the second part is not solved
+    fn part2(input: &Self::Input) -> Option<Answer>;
+}
@@ -0,0 +1,113 @@
+/// Disjoint sets over the elements `0..len`, with path compression and union by size.
+/// Elements of a puzzle are mapped to their index, e.g. their position in the input
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// Size of the component, only valid for roots
+    size: Vec<usize>,
+    components: usize
+}
+
+impl UnionFind {
+    /// Every element starts in a component of its own
+    pub fn new(len: usize) -> Self {
+        UnionFind { parent: (0..len).collect(), size: vec![1; len], components: len }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Representative of the component of `element`
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // point the whole path at the root
+        let mut current = element;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the components of `a` and `b`. Returns `false` if they were already connected
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // the smaller tree goes below the larger one
+        let (large, small) = if self.size[a] >= self.size[b] { (a, b) } else { (b, a) };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of components
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Number of elements in the component of `element`
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// The elements of every component, each in ascending order. Components are ordered by their smallest element
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut index_of_root: Vec<Option<usize>> = vec![None; self.len()];
+        let mut components: Vec<Vec<usize>> = vec![];
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *index_of_root[root].get_or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[index].push(element);
+        }
+
+        components.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.component_count(), 6);
+
+        assert!(sets.union(0, 3));
+        assert!(sets.union(3, 4));
+        assert!(sets.union(1, 2));
+        assert!(!sets.union(4, 0));
+
+        assert!(sets.connected(0, 4));
+        assert!(!sets.connected(0, 1));
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!(sets.component_size(4), 3);
+        assert_eq!(sets.component_size(5), 1);
+        assert_eq!(sets.components().collect::<Vec<_>>(), vec![vec![0, 3, 4], vec![1, 2], vec![5]]);
+    }
+}
@@ -0,0 +1,30 @@
+use std::cell::RefCell;
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+/// Records something odd about an input which does not stop the solution, e.g. converted line endings.
+/// The runner collects the warnings of the current thread with [`take`]
+pub fn warn(message: impl Into<String>) {
+    WARNINGS.with(|w| w.borrow_mut().push(message.into()));
+}
+
+/// Returns and clears the warnings recorded on this thread
+pub fn take() -> Vec<String> {
+    WARNINGS.with(|w| w.borrow_mut().drain(..).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take() {
+        warn("first");
+        warn(String::from("second"));
+
+        assert_eq!(take(), vec!["first", "second"]);
+        assert!(take().is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_common::error::parse_number;
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Movement>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        lines.iter().enumerate()
+            .map(|(index, line)| Movement::from(line).map_err(|e| e.on_line(index)))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        Some(puzzle_2::process(input).into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Direction {
+    Left,
+    Right
+}
+
+#[derive(Debug)]
+pub struct Movement {
+    direction: Direction,
+    amount: i32
+}
+
+impl Movement {
+    pub fn from(line: &str) -> Result<Self, ParseError> {
+        let direction = match line.chars().nth(0) {
+            Some('L') => Direction::Left,
+            Some('R') => Direction::Right,
+            _ => return Err(ParseError::new("Unknown direction", line.get(0..1).unwrap_or(line), 0))
+        };
+
+        let amount: i32 = parse_number(&line[1..], 1)?;
+
+        Ok(Movement { direction, amount })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = vec![String::from("L68"), String::from("R3x")];
+        let e = Day1::parse(&lines).unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "3x"));
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_common::trace;
+use crate::{Direction, Movement};
+
+pub fn process(movements: &[Movement]) -> usize {
+    let mut current_pos = 50;
+    let mut zero_pos_counter = 0;
+
+    movements.iter().for_each(|movement| {
+        current_pos = move_dial(current_pos, movement);
+        trace!("{:?} {} -> position {}", movement.direction, movement.amount, current_pos);
+        if current_pos == 0 {
+            zero_pos_counter += 1;
+        }
+    });
+
+    zero_pos_counter
+}
+
+fn move_dial(c: i32, m: &Movement) -> i32 {
+    let amount = m.amount % 100; // ignore full turn around
+
+    if m.direction == Direction::Right {
+        (c + amount) % 100
+    } else {
+        let n = c - amount;
+        if n < 0 {
+            100 + n
+        } else {
+            n
+        }
+    }
+}
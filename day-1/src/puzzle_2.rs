@@ -0,0 +1,46 @@
+use aoc_common::trace;
+use crate::{Direction, Movement};
+
+pub fn process(movements: &[Movement]) -> i32 {
+    let mut current_pos = 50;
+    let mut zero_hits_counter = 0;
+
+    movements.iter().for_each(|movement| {
+        let (new_current_pos, zero_hits) = move_dial(current_pos, movement);
+        trace!("{:?} {} -> position {}, {} zero hit(s)", movement.direction, movement.amount, new_current_pos, zero_hits);
+
+        current_pos = new_current_pos;
+        zero_hits_counter += zero_hits;
+    });
+
+    zero_hits_counter
+}
+
+fn move_dial(current_pos: i32, m: &Movement) -> (i32, i32) {
+    let mut zero_hits = m.amount / 100; // number of full turns
+    let amount = m.amount % 100; // ignore full turn around
+
+    if m.direction == Direction::Right {
+        let n = (current_pos + amount) % 100;
+        if n < current_pos {
+            zero_hits += 1;
+        }
+        (n, zero_hits)
+    } else {
+        let t_pos = current_pos - amount;
+        let n = if t_pos < 0 {
+            100 + t_pos
+        } else {
+            t_pos
+        };
+
+        if current_pos != 0 && n > current_pos { // ignore situations where we started at zero. Otherwise, we count 0 twice
+            zero_hits += 1;
+        }
+        if n == 0 { // dial hit 0 directly, no wrap around
+            zero_hits += 1;
+        }
+
+        (n, zero_hits)
+    }
+}
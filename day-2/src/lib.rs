@@ -0,0 +1,28 @@
+use aoc_common::{Answer, Interval, ParseError, Solution};
+use aoc_common::error::split_columns;
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// The ranges as listed, overlapping ranges are not merged because the ids are counted per range
+    type Input = Vec<Interval>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        let first_line = lines.first().unwrap(); // input has online one line
+
+        split_columns(first_line, ',')
+            .map(|(column, range)| Interval::parse(range).map_err(|e| e.shifted(column)))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        Some(puzzle_2::process(input).into())
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_common::Interval;
+use aoc_common::checked::{self, Site};
+use aoc_common::trace;
+
+pub fn process(ranges: &[Interval]) -> i64 {
+    checked::sum(ranges.iter().map(invalid_ids), &Site::new(concat!(module_path!(), "::process")))
+}
+
+fn invalid_ids(range: &Interval) -> i64 {
+    let ids: Vec<i64> = (range.start..=range.end).filter(|id| {
+        let id = id.to_string();
+
+        if id.len() % 2 == 1 {
+            return false;
+        }
+
+        let ls = id.get(0..id.len()/2).unwrap();
+        let rs = id.get(id.len()/2..id.len()).unwrap();
+
+        // number is invalid if it's repeated. E.g. 123123
+        // split in half. Both half's  must be identical
+        ls.eq(rs)
+    }).collect();
+    trace!("{}: {} invalid id(s)", range, ids.len());
+
+    checked::sum(ids, &Site::new(concat!(module_path!(), "::invalid_ids")))
+}
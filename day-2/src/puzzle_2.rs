@@ -0,0 +1,44 @@
+use aoc_common::Interval;
+use aoc_common::checked::{self, Site};
+use aoc_common::trace;
+
+pub fn process(ranges: &[Interval]) -> i64 {
+    checked::sum(ranges.iter().map(invalid_ids), &Site::new(concat!(module_path!(), "::process")))
+}
+
+fn invalid_ids(range: &Interval) -> i64 {
+    let ids: Vec<i64> = (range.start..=range.end).filter(|id| {
+        let id = id.to_string();
+        for slice_size in 2..=id.len() {
+            if is_invalid(id.as_str(), slice_size) {
+                return true;
+            }
+        }
+
+        false
+    }).collect();
+    trace!("{}: {} invalid id(s)", range, ids.len());
+
+    checked::sum(ids, &Site::new(concat!(module_path!(), "::invalid_ids")))
+}
+
+fn is_invalid(id: &str, num_slices: usize) -> bool {
+    if !id.len().is_multiple_of(num_slices) {
+        return false; // if length of id is not multiplicable of num_slices it cannot be a invalid pattern
+    }
+    let mut slices: Vec<&str> = vec![];
+
+    let slice_length = id.len() / num_slices;
+    for slice in 0..num_slices {
+        let slice_start = slice * slice_length;
+        let slice_end = (slice + 1) * slice_length;
+
+        let sub_str = id.get(slice_start..slice_end).unwrap();
+
+        slices.push(sub_str);
+    }
+    let first_element = slices.first().unwrap();
+    slices.iter().all(|e| {
+        first_element.eq(e)
+    })
+}
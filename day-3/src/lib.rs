@@ -0,0 +1,64 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Bank>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        lines.iter().enumerate()
+            .map(|(index, l)| Bank::from(l, index))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        Some(puzzle_2::process(input).into())
+    }
+}
+
+/// Part 2 turns on twelve batteries per bank, part 1 only two
+pub const MIN_BATTERIES: usize = 12;
+
+pub struct Bank {
+    batteries: Vec<u8>,
+    /// Index of the input line, for overflow reports
+    line: usize
+}
+
+impl Bank {
+    pub fn from(text: &str, line: usize) -> Result<Self, ParseError> {
+        let batteries: Vec<u8> = text.chars().enumerate().map(|(column, c)| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| ParseError::new("Expected a digit", &c.to_string(), column).on_line(line))
+        }).collect::<Result<_, _>>()?;
+
+        if batteries.len() < MIN_BATTERIES {
+            let message = format!("Expected at least {} batteries, found {}", MIN_BATTERIES, batteries.len());
+            return Err(ParseError::new(&message, text, 0).on_line(line));
+        }
+
+        Ok(Bank { batteries, line })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = vec![String::from("987654321111111"), String::from("12345")];
+        let e = Day3::parse(&lines).err().unwrap();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "12345"));
+        assert_eq!(e.message, "Expected at least 12 batteries, found 5");
+    }
+}
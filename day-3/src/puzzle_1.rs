@@ -0,0 +1,23 @@
+use aoc_common::checked::{self, Site};
+use aoc_common::trace;
+use crate::Bank;
+
+pub fn process(banks: &[Bank]) -> u64 {
+    let joltages = banks.iter().map(|bank| {
+        let joltage = joltage(bank);
+        trace!("line {}: joltage {}", bank.line + 1, joltage);
+        joltage
+    });
+
+    checked::sum(joltages, &Site::new(concat!(module_path!(), "::process")))
+}
+
+fn joltage(bank: &Bank) -> u64 {
+    let largest_tenth = bank.batteries[0..bank.batteries.len() - 1].iter().max().unwrap();
+    let largest_pos = bank.batteries[0..bank.batteries.len() - 1]
+        .iter().position(|e| e.eq(largest_tenth)).unwrap();
+    let largest_oneth = bank.batteries[largest_pos + 1..bank.batteries.len()].iter().max().unwrap();
+
+
+    *largest_tenth as u64 * 10 + *largest_oneth as u64
+}
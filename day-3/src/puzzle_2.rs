@@ -0,0 +1,38 @@
+use aoc_common::checked::{self, Site};
+use aoc_common::trace;
+use crate::Bank;
+
+pub fn process(banks: &[Bank]) -> u64 {
+    let joltages = banks.iter().map(|bank| {
+        let joltage = joltage(bank);
+        trace!("line {}: joltage {}", bank.line + 1, joltage);
+        joltage
+    });
+
+    checked::sum(joltages, &Site::new(concat!(module_path!(), "::process")))
+}
+
+fn joltage(bank: &Bank) -> u64 {
+    let mut digits: Vec<u8> = vec![];
+    let mut search_pos = 0;
+
+    for n_digit in (1..=12).rev() {
+        let l = bank.batteries[search_pos..=bank.batteries.len() - n_digit].iter().max().unwrap();
+        let l_pos = bank.batteries[search_pos..=bank.batteries.len() - n_digit]
+            .iter().position(|e| e.eq(l)).unwrap();
+        digits.push(*l);
+        search_pos = search_pos + l_pos + 1;
+    }
+
+    // compute value
+    let site = Site::new(concat!(module_path!(), "::joltage")).on_line(bank.line);
+    let mut r = 0;
+    for (index, value) in digits.iter().enumerate() {
+        let p = 12 - index - 1;
+        let v = checked::mul((*value) as u64, checked::pow(10u64, p, &site), &site);
+
+        r = checked::add(r, v, &site);
+    }
+
+    r
+}
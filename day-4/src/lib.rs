@@ -0,0 +1,59 @@
+use aoc_common::grid::{Grid, Position};
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Map;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Map::from(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        Some(puzzle_2::process(input).into())
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    /// Whether a cell holds a roll
+    rolls: Grid<bool>
+}
+
+impl Map {
+    pub fn from(lines: &[String]) -> Result<Self, ParseError> {
+        let rolls = Grid::parse(lines, |symbol| match symbol {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None
+        })?;
+
+        Ok(Map { rolls })
+    }
+
+    pub fn remove_rolls(&mut self, rolls: &[Position]) {
+        for &roll in rolls {
+            self.rolls[roll] = false;
+        }
+    }
+
+    pub fn reachable_rolls(&self) -> Vec<Position> {
+        let removable_rolls: Vec<Position> = self.rolls.find_all(&true).filter(|&roll| {
+            let num_neighbours = self.rolls.neighbours8(roll)
+                .filter(|&neighbour| self.rolls[neighbour])
+                .count();
+
+            num_neighbours < 4
+        }).collect();
+
+        removable_rolls
+    }
+}
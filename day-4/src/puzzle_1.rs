@@ -0,0 +1,5 @@
+use crate::Map;
+
+pub fn process(map: &Map) -> usize {
+    map.reachable_rolls().len()
+}
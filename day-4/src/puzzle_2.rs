@@ -0,0 +1,21 @@
+use aoc_common::debug;
+use crate::Map;
+
+pub fn process(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut total_removed_rolls = 0;
+
+    for wave in 1.. {
+        let r_rolls = map.reachable_rolls();
+        total_removed_rolls += r_rolls.len();
+        debug!("wave {}: {} roll(s) removed, {} in total", wave, r_rolls.len(), total_removed_rolls);
+
+        map.remove_rolls(&r_rolls);
+
+        if r_rolls.is_empty() {
+            break;
+        }
+    }
+
+    total_removed_rolls
+}
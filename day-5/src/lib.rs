@@ -0,0 +1,52 @@
+use aoc_common::{Answer, Interval, IntervalSet, ParseError, Solution};
+use aoc_common::error::parse_number;
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Inventory;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        let Some(separator) = lines.iter().position(|line| line.is_empty()) else {
+            return Err(ParseError::new("Expected an empty line between ranges and ids", "", 0).on_line(lines.len()));
+        };
+        let database = Database::from(&lines[..separator])?;
+
+        let ids: Vec<u64> = lines[separator + 1..].iter().enumerate()
+            .map(|(index, str_id)| parse_number(str_id, 0).map_err(|e| e.on_line(separator + 1 + index)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Inventory { database, ids })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        Some(puzzle_2::process(input).into())
+    }
+}
+
+pub struct Inventory {
+    database: Database,
+    ids: Vec<u64>
+}
+
+pub struct Database {
+    /// Fresh ids, overlapping ranges of the input are merged
+    ranges: IntervalSet<u64>
+}
+
+impl Database {
+    pub fn from(lines: &[String]) -> Result<Self, ParseError> {
+        let ranges = lines.iter().enumerate()
+            .map(|(index, l)| Interval::parse(l).map_err(|e| e.on_line(index)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Database { ranges })
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_common::trace;
+use crate::{Database, Inventory};
+
+pub fn process(inventory: &Inventory) -> usize {
+    inventory.ids.iter().filter(|&&id| {
+        let fresh = inventory.database.is_fresh(id);
+        trace!("id {}: {}", id, if fresh { "fresh" } else { "spoiled" });
+        fresh
+    }).count()
+}
+
+impl Database {
+    pub fn is_fresh(&self, id: u64) -> bool {
+        self.ranges.contains(id)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::debug;
+use crate::Inventory;
+
+pub fn process(inventory: &Inventory) -> u64 {
+    debug!("{} range(s) after merging", inventory.database.ranges.iter().count());
+    inventory.database.ranges.len()
+}
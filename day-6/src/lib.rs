@@ -0,0 +1,183 @@
+use aoc_common::{trace, Answer, InputMode, ParseError, Solution};
+use aoc_common::checked::{self, Site};
+use aoc_common::error::{parse_number, split_columns};
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Worksheet;
+
+    // the second part reads the numbers column by column, so spaces must survive loading
+    const INPUT_MODE: InputMode = InputMode::Preserved;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        let (last_line, rows) = lines.split_last().unwrap(); // operands are in the last line
+
+        for (index, row) in rows.iter().enumerate() {
+            let invalid = row.chars().enumerate().find(|(_, c)| !c.is_ascii_digit() && *c != ' ');
+            if let Some((column, c)) = invalid {
+                return Err(ParseError::new("Expected a digit", &c.to_string(), column).on_line(index));
+            }
+        }
+
+        let mut operands: Vec<Operand> = vec![];
+        let mut columns: Vec<usize> = vec![];
+        for (column, o) in split_columns(last_line, ' ').filter(|(_, o)| !o.is_empty()) {
+            operands.push(Operand::from(o).map_err(|e| e.shifted(column).on_line(rows.len()))?);
+            columns.push(column);
+        }
+
+        let horizontal = read_rows(rows, operands.len())?;
+        let vertical = read_columns(rows, operands.len())?;
+
+        Ok(Worksheet { horizontal, vertical, operands, columns, rows: rows.len() })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        Some(puzzle_2::process(input).into())
+    }
+}
+
+/// Both parts read the numbers differently, so both readings are kept
+#[derive(Debug)]
+pub struct Worksheet {
+    /// Numbers of each problem, read row by row
+    horizontal: Vec<Vec<i64>>,
+    /// Numbers of each problem, read column by column
+    vertical: Vec<Vec<i64>>,
+    operands: Vec<Operand>,
+    /// Column of each operand in the last line
+    columns: Vec<usize>,
+    /// Number of rows above the operands
+    rows: usize
+}
+
+impl Worksheet {
+    /// Input position of the problem with the given index, reported on overflows
+    fn site(&self, problem: usize) -> Site {
+        Site::new(concat!(module_path!(), "::MathProblem::value"))
+            .on_line(self.rows)
+            .at_column(self.columns[problem])
+    }
+}
+
+/// Every row holds one number per problem
+fn read_rows(rows: &[String], problems: usize) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut horizontal: Vec<Vec<i64>> = vec![vec![]; problems];
+
+    for (index, row) in rows.iter().enumerate() {
+        let numbers: Vec<(usize, &str)> = split_columns(row, ' ').filter(|(_, n)| !n.is_empty()).collect();
+        if numbers.len() != problems {
+            let message = format!("Expected {} numbers, found {}", problems, numbers.len());
+            return Err(ParseError::new(&message, row, 0).on_line(index));
+        }
+
+        for (problem, (column, n)) in numbers.into_iter().enumerate() {
+            horizontal[problem].push(parse_number(n, column).map_err(|e| e.on_line(index))?);
+        }
+    }
+
+    Ok(horizontal)
+}
+
+/// Every character column holds one number read top to bottom. Problems are separated by empty columns
+fn read_columns(rows: &[String], problems: usize) -> Result<Vec<Vec<i64>>, ParseError> {
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let mut vertical: Vec<Vec<i64>> = vec![vec![]];
+
+    for column in 0..width {
+        // rows were checked to be ASCII, shorter rows are filled with spaces
+        let digits: Vec<(usize, char)> = rows.iter().enumerate()
+            .filter_map(|(index, row)| row.as_bytes().get(column).map(|&b| (index, b as char)))
+            .filter(|(_, c)| *c != ' ')
+            .collect();
+
+        match digits.first() {
+            None => vertical.push(vec![]),
+            Some(&(top, _)) => {
+                let number: String = digits.iter().map(|(_, c)| c).collect();
+                vertical.last_mut().unwrap().push(parse_number(&number, column).map_err(|e| e.on_line(top))?);
+            }
+        }
+    }
+    vertical.retain(|numbers| !numbers.is_empty());
+
+    if vertical.len() != problems {
+        let message = format!("Expected {} problems separated by empty columns, found {}", problems, vertical.len());
+        return Err(ParseError::new(&message, "", 0).on_line(rows.len()));
+    }
+
+    Ok(vertical)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operand {
+    Plus,
+    Multiply
+}
+
+impl Operand {
+    pub fn from(o: &str) -> Result<Self, ParseError> {
+        match o {
+            "+" => Ok(Operand::Plus),
+            "*" => Ok(Operand::Multiply),
+            _ => Err(ParseError::new("Unknown operand", o, 0))
+        }
+    }
+}
+
+pub struct MathProblem {
+    numbers: Vec<i64>,
+    operand: Operand,
+    site: Site
+}
+
+impl MathProblem {
+    pub fn from(numbers: &[i64], operand: Operand, site: Site) -> Self {
+        let n = numbers.to_vec();
+
+        MathProblem { numbers: n, operand, site }
+    }
+
+    pub fn value(&self) -> i64 {
+        let numbers = self.numbers.iter().copied();
+        let value = match self.operand {
+            Operand::Plus => checked::sum(numbers, &self.site),
+            Operand::Multiply => checked::product(numbers, &self.site),
+        };
+        trace!("{:?} of {:?} = {}", self.operand, self.numbers, value);
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = vec![String::from("1 2"), String::from("+ -")];
+        let e = Day6::parse(&lines).unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "-"));
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        let lines: Vec<String> = ["1 2", "3 4 5", "* +"].map(String::from).to_vec();
+        let e = Day6::parse(&lines).unwrap_err();
+        assert_eq!((e.line, e.message.as_str()), (2, "Expected 2 numbers, found 3"));
+
+        let lines: Vec<String> = ["1 99999999999999999999", "* +"].map(String::from).to_vec();
+        let e = Day6::parse(&lines).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 3, "99999999999999999999"));
+    }
+}
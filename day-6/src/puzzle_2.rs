@@ -0,0 +1,11 @@
+use std::iter::zip;
+use aoc_common::checked::{self, Site};
+use crate::{MathProblem, Worksheet};
+
+pub fn process(worksheet: &Worksheet) -> i64 {
+    let values = zip(&worksheet.vertical, &worksheet.operands).enumerate()
+        .map(|(index, (n, o))| MathProblem::from(n, *o, worksheet.site(index)))
+        .map(|mp| mp.value());
+
+    checked::sum(values, &Site::new(concat!(module_path!(), "::process")))
+}
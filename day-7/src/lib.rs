@@ -0,0 +1,83 @@
+use aoc_common::grid::{Grid, Position};
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Map;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Map::from(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        Some(puzzle_2::process(input).into())
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    cells: Grid<Cell>,
+    beam_start: Position
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Cell {
+    Empty,
+    Start,
+    Splitter
+}
+
+impl Map {
+    pub fn from(lines: &[String]) -> Result<Self, ParseError> {
+        let cells = Grid::parse(lines, |symbol| match symbol {
+            'S' => Some(Cell::Start),
+            '^' => Some(Cell::Splitter),
+            '.' => Some(Cell::Empty),
+            _ => None
+        })?;
+        let starts: Vec<Position> = cells.find_all(&Cell::Start).collect();
+        let beam_start = match starts[..] {
+            [start] => start,
+            [] => return Err(ParseError::new("Expected a start S", lines.first().map_or("", |l| l), 0)),
+            [_, (row, column), ..] => {
+                return Err(ParseError::new("Expected only one start S", "S", column as usize).on_line(row as usize));
+            }
+        };
+
+        Ok(Map { cells, beam_start })
+    }
+
+    fn is_splitter(&self, position: Position) -> bool {
+        self.cells.get(position) == Some(&Cell::Splitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = vec![String::from("..S.."), String::from(".^.x.")];
+        let e = Day7::parse(&lines).unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, "x"));
+    }
+
+    #[test]
+    fn test_start() {
+        let e = Day7::parse(&[String::from("....."), String::from(".^...")]).unwrap_err();
+        assert_eq!((e.line, e.message.as_str()), (1, "Expected a start S"));
+
+        let e = Day7::parse(&[String::from("..S.."), String::from(".^.S.")]).unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 4, "Expected only one start S"));
+    }
+}
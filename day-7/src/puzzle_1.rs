@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+use aoc_common::{debug, trace};
+use crate::Map;
+
+pub fn process(map: &Map) -> usize {
+    map.count_beam_splits()
+}
+
+impl Map {
+    pub fn count_beam_splits(&self) -> usize {
+        let mut beam_splits = 0;
+
+        let mut active_beam_heads: HashSet<(i64, i64)> = HashSet::new();
+        active_beam_heads.insert((
+            self.beam_start.0 + 1,
+            self.beam_start.1
+        ));
+
+        for step in 1.. {
+            if active_beam_heads.is_empty() {
+                break;
+            }
+            debug!("step {}: {} beam(s), {} split(s) so far", step, active_beam_heads.len(), beam_splits);
+            trace!("frontier {:?}", sorted(&active_beam_heads));
+            let current_beams = active_beam_heads.clone();
+            current_beams.iter().for_each(|bh| {
+                let evolve_result = self.evolve_beam(*bh);
+                active_beam_heads.remove(bh);
+
+                match evolve_result {
+                    BeamPosition::None => {}, // nothing to do
+                    BeamPosition::One(n_bh) => {
+                        active_beam_heads.insert(n_bh);
+                    },
+                    BeamPosition::Split(n_bh1, n_bh2) => {
+                        beam_splits += 1;
+                        active_beam_heads.insert(n_bh1);
+                        active_beam_heads.insert(n_bh2);
+                    }
+                }
+            });
+        }
+
+        beam_splits
+    }
+
+    fn evolve_beam(&self, beam_pos: (i64, i64)) -> BeamPosition {
+        if !self.cells.contains(beam_pos) {
+            return BeamPosition::None;
+        }
+
+        let next_pos = (
+            beam_pos.0 + 1,
+            beam_pos.1
+        );
+        if !self.is_splitter(next_pos) {
+            // no split, simply move forward
+            return BeamPosition::One(next_pos);
+        }
+
+        // split happened
+        let b1 = (
+            next_pos.0,
+            next_pos.1 - 1
+        );
+        let b2 = (
+            next_pos.0,
+            next_pos.1 + 1
+        );
+
+        BeamPosition::Split(b1, b2)
+    }
+}
+
+fn sorted(beams: &HashSet<(i64, i64)>) -> Vec<(i64, i64)> {
+    let mut beams: Vec<(i64, i64)> = beams.iter().copied().collect();
+    beams.sort();
+    beams
+}
+
+#[derive(Debug)]
+pub enum BeamPosition {
+    None,
+    One((i64, i64)),
+    Split((i64, i64), (i64, i64))
+}
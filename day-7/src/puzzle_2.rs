@@ -0,0 +1,50 @@
+use aoc_common::{debug, Memo};
+use aoc_common::grid::Position;
+use crate::Map;
+
+pub fn process(map: &Map) -> usize {
+    let mut memo = Memo::new();
+    let paths = map.count_paths(map.beam_start, &mut memo);
+    debug!("counted paths with {}", memo.stats());
+
+    paths
+}
+
+impl Map {
+    /// Number of paths a beam starting at `beam` can take to the bottom of the map
+    pub fn count_paths(&self, beam: Position, memo: &mut Memo<Position, usize>) -> usize {
+        memo.get_or_compute(beam, |memo| match self.next_split(&beam) {
+            BeamSplit::None => 1,
+            BeamSplit::Split(s1, s2) => self.count_paths(s1, memo) + self.count_paths(s2, memo)
+        })
+    }
+
+    fn next_split(&self, beam_pos: &(i64, i64)) -> BeamSplit {
+        let mut search_pos = (beam_pos.0, beam_pos.1); // we start searching at current pos
+
+        loop {
+            if !self.cells.contains(search_pos) {
+                return BeamSplit::None;
+            }
+            if self.is_splitter(search_pos) {
+                let b1 = (
+                    search_pos.0,
+                    search_pos.1 - 1
+                );
+                let b2 = (
+                    search_pos.0,
+                    search_pos.1 + 1
+                );
+
+                return BeamSplit::Split(b1, b2);
+            }
+            search_pos = (search_pos.0 + 1, search_pos.1);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BeamSplit {
+    None, // if beam is out of map
+    Split((i64, i64), (i64, i64))
+}
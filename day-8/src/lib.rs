@@ -0,0 +1,66 @@
+use aoc_common::{Answer, Params, ParseError, Point3, Solution};
+use itertools::Itertools;
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+/// Number of shortest connections which are made in the first part.
+/// The example uses the parameter `max_steps` instead
+const MAX_STEPS: usize = 1000;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Playground;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Self::parse_with(lines, &Params::new())
+    }
+
+    fn parse_with(lines: &[String], params: &Params) -> Result<Self::Input, ParseError> {
+        let junctions = lines.iter().enumerate()
+            .map(|(index, l)| Junction::parse(l).map_err(|e| e.on_line(index)))
+            .collect::<Result<Vec<Junction>, ParseError>>()?;
+
+        Ok(Playground { junctions, max_steps: params.get_or("max_steps", MAX_STEPS) })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(&input.junctions, input.max_steps).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        puzzle_2::process(&input.junctions).map(Answer::from)
+    }
+}
+
+pub struct Playground {
+    junctions: Vec<Junction>,
+    max_steps: usize
+}
+
+/// All pairs of junctions as indices into `junctions`, the closest pair first
+fn connections(junctions: &[Junction]) -> Vec<(usize, usize)> {
+    (0..junctions.len()).tuple_combinations()
+        .sorted_by_key(|&(j1, j2)| junctions[j1].squared_euclidean(&junctions[j2]))
+        .collect()
+}
+
+/// Position of a junction box, parsed from `x,y,z`
+pub type Junction = Point3;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_inputs() {
+        let lines = vec![String::from("1,2,3"), String::from("4,5,6")];
+        let input = Day8::parse(&lines).unwrap();
+        assert_eq!(Day8::part1(&input).to_string(), "2");
+        assert_eq!(Day8::part2(&input).map(|a| a.to_string()), Some(String::from("4")));
+
+        let input = Day8::parse(&lines[..1]).unwrap();
+        assert_eq!(Day8::part2(&input), None);
+    }
+}
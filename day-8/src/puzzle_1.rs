@@ -0,0 +1,20 @@
+use aoc_common::{debug, trace, UnionFind};
+use itertools::Itertools;
+use crate::{connections, Junction};
+
+pub fn process(junctions: &[Junction], max_steps: usize) -> usize {
+    let connections = connections(junctions);
+    // small inputs have fewer pairs than connections to make
+    let mut circuits = UnionFind::new(junctions.len());
+    for &(j1, j2) in connections.iter().take(max_steps) {
+        if circuits.union(j1, j2) {
+            trace!("connected {} and {}, {} circuit(s) left", junctions[j1], junctions[j2], circuits.component_count());
+        }
+    }
+    debug!("{} circuit(s) after {} connection(s)", circuits.component_count(), max_steps);
+
+    circuits.components().map(|c| c.len())
+        .sorted().rev()
+        .take(3)
+        .product()
+}
@@ -0,0 +1,21 @@
+use aoc_common::{trace, UnionFind};
+use crate::{connections, Junction};
+
+/// `None` if the junctions never form a single circuit, which needs at least two of them
+pub fn process(junctions: &[Junction]) -> Option<i64> {
+    let mut circuits = UnionFind::new(junctions.len());
+
+    // connect the closest pairs until all junctions form a single circuit
+    for (j1, j2) in connections(junctions) {
+        if !circuits.union(j1, j2) {
+            continue;
+        }
+        trace!("connected {} and {}, {} circuit(s) left", junctions[j1], junctions[j2], circuits.component_count());
+
+        if circuits.component_count() == 1 {
+            return Some(junctions[j1].x * junctions[j2].x);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use aoc_common::{Answer, ParseError, Point2, Solution};
+
+pub mod puzzle_1;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Map;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Map::from(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    /// Red tiles and the index of the input line they are listed on
+    tiles: HashMap<Point2, usize>
+}
+
+impl Map {
+    pub fn from(lines: &[String]) -> Result<Self, ParseError> {
+        let tiles: HashMap<Point2, usize> = lines.iter().enumerate()
+            .map(|(index, l)| Point2::parse(l).map(|tile| (tile, index)).map_err(|e| e.on_line(index)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Map { tiles })
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_common::checked::{self, Site};
+use aoc_common::debug;
+use itertools::Itertools;
+use crate::Map;
+
+pub fn process(map: &Map) -> i64 {
+    let area = map.largest_rect();
+    debug!("largest rectangle of {} red tile(s): {}", map.tiles.len(), area);
+
+    area
+}
+
+impl Map {
+    pub fn largest_rect(&self) -> i64 {
+        self.tiles.iter().cartesian_product(self.tiles.iter())
+            .map(|((t1, line), (t2, _))| {
+                let site = Site::new(concat!(module_path!(), "::Map::largest_rect")).on_line(*line);
+                let x_length = checked::add(checked::abs(checked::sub(t1.x, t2.x, &site), &site), 1, &site);
+                let y_length = checked::add(checked::abs(checked::sub(t1.y, t2.y, &site), &site), 1, &site);
+
+                checked::mul(x_length, y_length, &site)
+            })
+            .sorted()
+            .max().unwrap()
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod puzzle_1;
+pub mod puzzle_2;
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    type Input = Vec<String>;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        puzzle_1::process(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Answer> {
+        puzzle_2::process(input).map(Answer::from)
+    }
+}
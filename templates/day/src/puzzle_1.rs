@@ -0,0 +1,3 @@
+pub fn process(_lines: &[String]) -> usize {
+    0
+}
@@ -0,0 +1,4 @@
+/// `None` until part 2 is solved
+pub fn process(_lines: &[String]) -> Option<usize> {
+    None
+}